@@ -0,0 +1,54 @@
+#[macro_use]
+mod input;
+
+use core::time::Duration;
+
+use algorithms::AdaptiveWriter;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+// Default random data size.
+const COUNT: usize = 1000;
+
+// Compare the adaptive writer against each of its fixed strategies. The
+// writer is reused across iterations, so it learns the distribution during
+// the warm up, and the `mixed` data changes the distribution within a pass.
+macro_rules! bench {
+    ($fn:ident, $name:literal, $($strategy:expr),*) => {
+        fn $fn(criterion: &mut Criterion) {
+            let mut group = criterion.benchmark_group($name);
+            group.measurement_time(Duration::from_secs(5));
+            let seed = input::seed();
+
+            let mut data: Vec<u64> = Vec::new();
+            $(
+                data.extend(input::random_data::<u64>($name, $strategy, COUNT, seed));
+            )*
+
+            group.bench_function("write_u64_adaptive_c", |bench| {
+                let mut writer = AdaptiveWriter::new();
+                let mut buffer = [b'0'; input::BUFFER_SIZE];
+                bench.iter(|| {
+                    data.iter().for_each(|&x| {
+                        black_box(writer.write_u64::<true>(x, &mut buffer));
+                    })
+                })
+            });
+            write_u64_generator!(group, jeaiii64_better_v4, data.iter(), true);
+            write_u64_generator!(group, jeaiii64_better_v5, data.iter(), true);
+            write_u64_generator!(group, swar64, data.iter(), true);
+        }
+    };
+}
+
+bench!(simple, "adaptive:simple", input::RandomGen::Simple);
+bench!(large, "adaptive:large", input::RandomGen::Large);
+bench!(safe_int, "adaptive:safe_int", input::RandomGen::SafeInt);
+bench!(
+    mixed,
+    "adaptive:mixed",
+    input::RandomGen::Simple,
+    input::RandomGen::SafeInt,
+    input::RandomGen::Large
+);
+criterion_group!(adaptive_benches, simple, large, safe_int, mixed);
+criterion_main!(adaptive_benches);
@@ -0,0 +1,164 @@
+//! Select the fastest registered algorithm for each type on the local CPU.
+//!
+//! This times every registered algorithm over random data, and writes the
+//! dispatch table used by the `autotune` feature, for example:
+//!
+//! ```bash
+//! cargo run --release --bin autotune -- --strategy log_uniform
+//! cargo build --features autotune
+//! ```
+//!
+//! The options are:
+//! - `--strategy NAME`: The random data, one of `uniform`, `simple`, `large`
+//!   or `log_uniform` (the default).
+//! - `--output PATH`: The generated file, by default, `src/dispatch_tuned.rs`.
+//!
+//! The seed can be set with `BENCH_SEED`, like the benches.
+
+#[macro_use]
+mod input;
+
+use std::fmt::Write;
+use std::hint::black_box;
+use std::path::PathBuf;
+use std::process;
+use std::time::{Duration, Instant};
+
+use input::RandomGen;
+
+// Random data size, the same as the benches.
+const COUNT: usize = 1000;
+
+// The number of timed rounds for each algorithm, where the fastest is used.
+const ROUNDS: usize = 15;
+
+// The minimum time for each round.
+const ROUND_TIME: Duration = Duration::from_millis(10);
+
+// Get the time in nanoseconds for a single pass over the data.
+fn measure<T: Copy, F: Fn(T, &mut [u8]) -> usize>(data: &[T], write: F) -> f64 {
+    let mut buffer = [b'0'; input::BUFFER_SIZE];
+    let mut pass = || {
+        for &x in data {
+            black_box(write(black_box(x), &mut buffer));
+        }
+    };
+
+    // Warm up, and find the number of passes for each round.
+    let mut passes = 1;
+    loop {
+        let start = Instant::now();
+        (0..passes).for_each(|_| pass());
+        if start.elapsed() >= ROUND_TIME {
+            break;
+        }
+        passes *= 2;
+    }
+
+    let mut best = f64::MAX;
+    for _ in 0..ROUNDS {
+        let start = Instant::now();
+        (0..passes).for_each(|_| pass());
+        let time = start.elapsed().as_nanos() as f64 / passes as f64;
+        best = best.min(time);
+    }
+    best
+}
+
+// Time every registered algorithm that can be dispatched to, which must be
+// variable-width and accept every value. Each is called with the `CHECKED`
+// mode used in the benches, so the calls are inlined like in the benches.
+macro_rules! tune {
+    (
+        $t:ident, $data:ident, $results:ident ;
+        $(
+            $name:ident,
+            $checked:ident,
+            $unchecked:ident,
+            $bench:literal,
+            $width:expr,
+            $min:expr,
+            $max:expr ;
+        )*
+    ) => {{
+        $(
+            let (min, max): ($t, $t) = ($min, $max);
+            if matches!($width, algorithms::Width::Variable) && min == 0 && max == $t::MAX {
+                let write = |x, buffer: &mut [u8]| algorithms::$name::<$bench>(x, buffer).len();
+                $results.push((stringify!($name), measure(&$data, write)));
+            }
+        )*
+    }};
+}
+
+// Time the algorithms for a type, returning them from fastest to slowest.
+macro_rules! tune_type {
+    ($t:ident, $registry:ident, $strategy:ident, $seed:ident) => {{
+        let data = input::type_from_random::<$t>($strategy, COUNT, $seed);
+        let mut results: Vec<(&'static str, f64)> = Vec::new();
+        algorithms::$registry!(tune, data, results);
+        results.sort_by(|x, y| x.1.total_cmp(&y.1));
+        eprintln!("{}:", stringify!($t));
+        for (name, time) in results.iter() {
+            eprintln!("    {:<24} {:>10.2} ns", name, time);
+        }
+        (stringify!($t), results)
+    }};
+}
+
+fn usage() -> ! {
+    eprintln!("usage: autotune [--strategy NAME] [--output PATH]");
+    process::exit(2);
+}
+
+fn main() {
+    let mut strategy_name = "log_uniform".to_string();
+    let mut output = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+    output.push("..");
+    output.push("src");
+    output.push("dispatch_tuned.rs");
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--strategy" => strategy_name = args.next().unwrap_or_else(|| usage()),
+            "--output" => output = args.next().unwrap_or_else(|| usage()).into(),
+            _ => usage(),
+        }
+    }
+    let strategy = match strategy_name.as_str() {
+        "uniform" => RandomGen::Uniform,
+        "simple" => RandomGen::Simple,
+        "large" => RandomGen::Large,
+        "log_uniform" => RandomGen::LogUniform,
+        _ => usage(),
+    };
+
+    let seed = input::seed();
+    eprintln!("strategy {} with {}={}", strategy_name, input::SEED_VAR, seed);
+    let types = [
+        tune_type!(u8, registry_u8, strategy, seed),
+        tune_type!(u16, registry_u16, strategy, seed),
+        tune_type!(u32, registry_u32, strategy, seed),
+        tune_type!(u64, registry_u64, strategy, seed),
+    ];
+
+    let mut source = String::new();
+    source.push_str("// This file is generated by `cargo run --release --bin autotune` in\n");
+    source.push_str("// `bench`, and is only used with the `autotune` feature. Do not edit it.\n");
+    writeln!(source, "// Strategy: {}, seed: {}.", strategy_name, seed).unwrap();
+    for (int_type, results) in types.iter() {
+        source.push_str("//\n");
+        writeln!(source, "// {}:", int_type).unwrap();
+        for (name, time) in results.iter() {
+            writeln!(source, "// - {}: {:.2} ns", name, time).unwrap();
+        }
+    }
+    source.push('\n');
+    for (int_type, results) in types.iter() {
+        let (name, _) = results[0];
+        writeln!(source, "pub(crate) use crate::{} as write_{};", name, int_type).unwrap();
+    }
+
+    std::fs::write(&output, source).unwrap();
+    eprintln!("wrote {}", output.display());
+}
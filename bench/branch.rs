@@ -0,0 +1,51 @@
+#[macro_use]
+mod input;
+
+use core::time::Duration;
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+use input::Order;
+
+// Default random data size.
+const COUNT: usize = 1000;
+
+// The constant digit counts, where every branch is predicted.
+const DIGITS: [u32; 3] = [1, 5, 10];
+
+// The order groups write the same values, only the order differs, so the
+// difference between them is the cost of branch mispredictions. The groups
+// with a constant digit count are the baseline without mispredictions.
+fn branch_u32(criterion: &mut Criterion) {
+    let seed = input::seed();
+    for digits in DIGITS {
+        let name = format!("branch:digits{}", digits);
+        let mut group = criterion.benchmark_group(&name);
+        group.measurement_time(Duration::from_secs(5));
+
+        let strategy = input::RandomGen::DigitLength(digits);
+        let data = input::random_data::<u32>(&name, strategy, COUNT, seed);
+
+        write_u32_generator!(group, jeaiii32_better, data.iter(), true);
+        write_u32_generator!(group, jeaiii32_digits, data.iter(), true);
+        write_u32_generator!(group, alexandrescu32, data.iter(), false);
+    }
+
+    for order in [Order::Sorted, Order::Grouped, Order::Alternating, Order::Shuffled] {
+        let name = format!("branch:{}", order.name());
+        let mut group = criterion.benchmark_group(&name);
+        group.measurement_time(Duration::from_secs(5));
+
+        // The same seed gives the same values for each group.
+        let strategy = input::RandomGen::LogUniform;
+        let data = input::random_data::<u32>(&name, strategy, COUNT, seed);
+        let data = input::reorder(&data, order, seed);
+
+        write_u32_generator!(group, jeaiii32_better, data.iter(), true);
+        write_u32_generator!(group, jeaiii32_digits, data.iter(), true);
+        write_u32_generator!(group, alexandrescu32, data.iter(), false);
+    }
+}
+
+criterion_group!(branch_benches, branch_u32);
+criterion_main!(branch_benches);
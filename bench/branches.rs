@@ -0,0 +1,81 @@
+//! Print the branch histogram of the jeaiii writers for each random dataset.
+//!
+//! This requires the `instrument` feature, which counts the hits for each
+//! branch of the writers, for example:
+//!
+//! ```bash
+//! cargo run --release --features instrument --bin branches
+//! ```
+//!
+//! The seed can be set with `BENCH_SEED`, like the benches.
+
+#[macro_use]
+mod input;
+
+use algorithms::BranchCounters;
+use input::RandomGen;
+
+// Random data size, the same as the benches.
+const COUNT: usize = 1000;
+
+// Every dataset, with the name used in the benches. The safe integer
+// datasets are only used for 64-bit types.
+const STRATEGIES: [(&str, RandomGen); 6] = [
+    ("uniform", RandomGen::Uniform),
+    ("simple", RandomGen::Simple),
+    ("large", RandomGen::Large),
+    ("log_uniform", RandomGen::LogUniform),
+    ("safe_int", RandomGen::SafeInt),
+    ("large_safe_int", RandomGen::LargeSafeInt),
+];
+
+// The number of datasets for 32-bit types.
+const STRATEGIES_U32: usize = 4;
+
+// Print the share of the hits for each branch, in a single line.
+fn print_histogram(counters: &BranchCounters) {
+    let total = counters.total().max(1) as f64;
+    let branches = counters.branches.iter().zip(counters.hits());
+    let shares: Vec<String> = branches
+        .map(|(label, hits)| format!("{} {:5.1}%", label, 100.0 * hits as f64 / total))
+        .collect();
+    println!("    {:<20} {}", counters.name, shares.join(", "));
+}
+
+// Write the data with each writer, and print its branch histogram.
+macro_rules! histograms {
+    ($t:ident, $name:ident, $strategy:ident, $seed:ident ; $($func:ident $counters:ident),*) => {{
+        let data = input::type_from_random::<$t>($strategy, COUNT, $seed);
+        let mut buffer = [b'0'; input::BUFFER_SIZE];
+        println!("{} ({}):", $name, stringify!($t));
+        $(
+            algorithms::reset_branch_counters();
+            data.iter().for_each(|&x| _ = algorithms::$func::<false>(x, &mut buffer));
+            print_histogram(&algorithms::$counters);
+        )*
+    }};
+}
+
+fn main() {
+    let seed = input::seed();
+    println!("{}={}", input::SEED_VAR, seed);
+    for &(name, strategy) in STRATEGIES[..STRATEGIES_U32].iter() {
+        histograms!(
+            u32, name, strategy, seed ;
+            jeaiii32_original JEAIII32_ORIGINAL,
+            jeaiii32_better JEAIII32_BETTER,
+            jeaiii32_digits JEAIII32_DIGITS
+        );
+    }
+    for &(name, strategy) in STRATEGIES.iter() {
+        histograms!(
+            u64, name, strategy, seed ;
+            jeaiii64_better JEAIII64_BETTER,
+            jeaiii64_better_v2 JEAIII64_BETTER_V2,
+            jeaiii64_better_v3 JEAIII64_BETTER_V3,
+            jeaiii64_better_v4 JEAIII64_BETTER_V4,
+            jeaiii64_better_v5 JEAIII64_BETTER_V5,
+            jeaiii64_better_v6 JEAIII64_BETTER_V6
+        );
+    }
+}
@@ -0,0 +1,65 @@
+#[macro_use]
+mod input;
+
+use core::time::Duration;
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion, SamplingMode};
+
+// Default random data size.
+const COUNT: usize = 1000;
+
+// The number of values written after each eviction in the cold benches.
+const COLD_BATCH: usize = 8;
+
+// Compare each algorithm with the tables in the cache (hot), and with the
+// cache evicted before every batch of `COLD_BATCH` values (cold). The larger
+// tables should be faster when hot, but pay for their footprint when cold,
+// while the table-free variants should be slower when hot, but faster when
+// cold.
+macro_rules! bench {
+    ($fn:ident, $name:literal, $t:ty, $($func:ident)*) => {
+        fn $fn(criterion: &mut Criterion) {
+            let mut group = criterion.benchmark_group($name);
+            group.measurement_time(Duration::from_secs(5));
+            let seed = input::seed();
+
+            let data = input::random_data::<$t>($name, input::RandomGen::Uniform, COUNT, seed);
+            $(
+                checked_generator!(group, concat!(stringify!($func), "_hot"), data.iter(), $func, false);
+            )*
+
+            group.finish();
+
+            // Every iteration walks the eviction buffer, which dominates the
+            // wall time, so use few iterations with the same count each.
+            let cold = format!("{}:cold_batch{}", $name, COLD_BATCH);
+            let mut group = criterion.benchmark_group(cold);
+            group.sampling_mode(SamplingMode::Flat);
+            group.sample_size(10);
+            group.warm_up_time(Duration::from_secs(1));
+            group.measurement_time(Duration::from_secs(5));
+            $(
+                let name = concat!(stringify!($func), "_cold");
+                cold_generator!(group, name, data.iter(), $func, false, COLD_BATCH);
+            )*
+        }
+    };
+}
+
+bench!(
+    cache_u32,
+    "cache:u32",
+    u32,
+    alexandrescu32 alexandrescu32_table3 alexandrescu32_table4 alexandrescu32_arith
+    jeaiii32_better jeaiii32_table4 jeaiii32_arith
+);
+bench!(
+    cache_u64,
+    "cache:u64",
+    u64,
+    alexandrescu64 alexandrescu64_table3 alexandrescu64_table4 alexandrescu64_arith
+    jeaiii64_better_v4 jeaiii64_table4 jeaiii64_arith
+);
+criterion_group!(cache_u32_benches, cache_u32);
+criterion_group!(cache_u64_benches, cache_u64);
+criterion_main!(cache_u32_benches, cache_u64_benches);
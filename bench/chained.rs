@@ -0,0 +1,64 @@
+#[macro_use]
+mod input;
+
+use core::time::Duration;
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+// Default random data size.
+const COUNT: usize = 1000;
+
+// Each input is XORed with the low bit of the last digit written for the
+// previous value, so every conversion depends on the previous one, and the
+// CPU cannot overlap them. The digits are passed through `black_box` first,
+// so the compiler cannot derive the carry from the input instead. Flipping
+// the low bit preserves the digit count exactly: it only moves an even value
+// up or an odd value down, and each digit count starts at 0 or at 10^k, which
+// is even, so the distribution of the digit counts matches the unchained data.
+macro_rules! chained_generator {
+    ($group:ident, $func:ident, $iter:expr, $checked:literal) => {{
+        use crate::input::BUFFER_SIZE;
+        let mut buffer: [u8; BUFFER_SIZE] = [b'0'; BUFFER_SIZE];
+        let suffix = if $checked { "_chained_c" } else { "_chained_u" };
+        let name = format!("write_u32_{}{}", stringify!($func), suffix);
+        $group.bench_function(name, |bench| {
+            bench.iter(|| {
+                let mut carry = 0u32;
+                $iter.for_each(|&x| {
+                    let digits = algorithms::$func::<$checked>(x ^ carry, &mut buffer);
+                    let digits = black_box(&*digits);
+                    carry = (digits[digits.len() - 1] & 1) as u32;
+                });
+                black_box(carry)
+            })
+        });
+    }};
+}
+
+// Compare the chained latency against the independent throughput, for
+// the same algorithms and data.
+macro_rules! bench {
+    ($fn:ident, $name:literal, $strategy:expr) => {
+        fn $fn(criterion: &mut Criterion) {
+            let mut group = criterion.benchmark_group($name);
+            group.measurement_time(Duration::from_secs(5));
+            let seed = input::seed();
+            let data = input::random_data::<u32>($name, $strategy, COUNT, seed);
+
+            chained_generator!(group, jeaiii32_10, data.iter(), true);
+            chained_generator!(group, jeaiii32_better, data.iter(), true);
+            chained_generator!(group, alexandrescu32, data.iter(), false);
+            chained_generator!(group, swar32, data.iter(), true);
+            write_u32_generator!(group, jeaiii32_10, data.iter(), true);
+            write_u32_generator!(group, jeaiii32_better, data.iter(), true);
+            write_u32_generator!(group, alexandrescu32, data.iter(), false);
+            write_u32_generator!(group, swar32, data.iter(), true);
+        }
+    };
+}
+
+bench!(simple, "chained:simple", input::RandomGen::Simple);
+bench!(large, "chained:large", input::RandomGen::Large);
+bench!(log_uniform, "chained:log_uniform", input::RandomGen::LogUniform);
+criterion_group!(chained_benches, simple, large, log_uniform);
+criterion_main!(chained_benches);
@@ -0,0 +1,155 @@
+//! Compare the criterion results against a baseline, to find regressions.
+//!
+//! Save a baseline, make the changes, and then compare, for example:
+//!
+//! ```bash
+//! BENCH_SEED=1234 cargo bench -- --save-baseline main
+//! BENCH_SEED=1234 cargo bench
+//! cargo run --release --bin compare -- --baseline main --threshold 5
+//! ```
+//!
+//! The random data is only the same for both runs with the same
+//! `BENCH_SEED`, otherwise, the changes include the difference in the data.
+//!
+//! This exits with a non-zero code if any algorithm slowed down by more
+//! than the threshold for any group, or if any result in the baseline is
+//! missing from the current results, so a renamed or removed bench cannot
+//! silently drop out of the comparison. The options are:
+//! - `--baseline NAME`: The baseline to compare against, by default, `base`,
+//!   which is the previous run.
+//! - `--current NAME`: The results to compare, by default, `new`, which is
+//!   the latest run.
+//! - `--threshold PERCENT`: The noise threshold, by default, `5`.
+//! - `--all`: Show every comparison, and not just those beyond the threshold.
+//! - `--dir PATH`: The criterion directory, by default, `target/criterion`.
+
+mod results;
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::process;
+
+use results::Measurement;
+
+// The result of a single comparison.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Status {
+    Regressed,
+    Improved,
+    Unchanged,
+}
+
+impl Status {
+    const fn name(self) -> &'static str {
+        match self {
+            Status::Regressed => "regressed",
+            Status::Improved => "improved",
+            Status::Unchanged => "unchanged",
+        }
+    }
+}
+
+// Classify the change in time, as a percent, against the threshold.
+fn status(change: f64, threshold: f64) -> Status {
+    if change > threshold {
+        Status::Regressed
+    } else if change < -threshold {
+        Status::Improved
+    } else {
+        Status::Unchanged
+    }
+}
+
+fn usage() -> ! {
+    eprintln!(concat!(
+        "usage: compare [--baseline NAME] [--current NAME] [--threshold PERCENT] ",
+        "[--all] [--dir PATH]"
+    ));
+    process::exit(2);
+}
+
+fn main() {
+    let mut baseline = "base".to_string();
+    let mut current = "new".to_string();
+    let mut threshold = 5.0;
+    let mut show_all = false;
+    let mut dir = results::criterion_dir();
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--baseline" => baseline = args.next().unwrap_or_else(|| usage()),
+            "--current" => current = args.next().unwrap_or_else(|| usage()),
+            "--threshold" => {
+                let value = args.next().unwrap_or_else(|| usage());
+                threshold = value.parse().unwrap_or_else(|_| usage());
+            },
+            "--all" => show_all = true,
+            "--dir" => dir = args.next().unwrap_or_else(|| usage()).into(),
+            _ => usage(),
+        }
+    }
+
+    let before: BTreeMap<String, Measurement> =
+        results::load(&dir, &baseline).into_iter().map(|x| (x.key(), x)).collect();
+    let after = results::load(&dir, &current);
+    if before.is_empty() || after.is_empty() {
+        eprintln!("no results for \"{}\" and \"{}\" in {}", baseline, current, dir.display());
+        process::exit(2);
+    }
+
+    let mut counts = [0usize; 3];
+    let mut added = 0;
+    println!("| Group | Algorithm | {} | {} | Change | Status |", baseline, current);
+    println!("|:--|:--|--:|--:|--:|:--|");
+    for measurement in &after {
+        let previous = match before.get(&measurement.key()) {
+            Some(previous) => previous,
+            None => {
+                added += 1;
+                continue;
+            },
+        };
+        let change = (measurement.time / previous.time - 1.0) * 100.0;
+        let status = status(change, threshold);
+        counts[status as usize] += 1;
+        if show_all || status != Status::Unchanged {
+            println!(
+                "| {} | `{}` | {} | {} | {:+.2}% | {} |",
+                measurement.group,
+                measurement.function,
+                results::format_time(previous.time),
+                results::format_time(measurement.time),
+                change,
+                status.name(),
+            );
+        }
+    }
+
+    let current_keys: BTreeSet<String> = after.iter().map(|x| x.key()).collect();
+    let missing: Vec<&Measurement> =
+        before.values().filter(|x| !current_keys.contains(&x.key())).collect();
+    if !missing.is_empty() {
+        println!();
+        println!("Missing from \"{}\":", current);
+        println!();
+        for measurement in &missing {
+            println!("- {} `{}`", measurement.group, measurement.function);
+        }
+    }
+
+    println!();
+    println!(
+        concat!(
+            "{} regressed, {} improved, {} unchanged (threshold {}%), ",
+            "{} not in the baseline, {} missing"
+        ),
+        counts[Status::Regressed as usize],
+        counts[Status::Improved as usize],
+        counts[Status::Unchanged as usize],
+        threshold,
+        added,
+        missing.len(),
+    );
+    if counts[Status::Regressed as usize] != 0 || !missing.is_empty() {
+        process::exit(1);
+    }
+}
@@ -0,0 +1,44 @@
+#[macro_use]
+mod input;
+
+use core::time::Duration;
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+// Select the corpus files, by default, every `.txt` and `.csv` file in
+// `data`. For example, `INTEGER_CORPUS=rows.csv,sizes.txt cargo bench`.
+const CORPUS_VAR: &str = "INTEGER_CORPUS";
+
+// Values larger than `u32::MAX` are only used for the u64 writers.
+fn corpus_u32(criterion: &mut Criterion) {
+    for name in input::corpus_files(CORPUS_VAR) {
+        let data: Vec<u32> = input::read_corpus(&name);
+        if data.is_empty() {
+            continue;
+        }
+        let mut group = criterion.benchmark_group(format!("corpus:{}:u32", name));
+        group.measurement_time(Duration::from_secs(5));
+
+        algorithms::registry_u32!(registry_generator, group, data);
+        fmt_generator!(group, "write_u32_fmt", data.iter());
+        itoa_generator!(group, "write_u32_itoa", data.iter());
+    }
+}
+
+fn corpus_u64(criterion: &mut Criterion) {
+    for name in input::corpus_files(CORPUS_VAR) {
+        let data: Vec<u64> = input::read_corpus(&name);
+        if data.is_empty() {
+            continue;
+        }
+        let mut group = criterion.benchmark_group(format!("corpus:{}:u64", name));
+        group.measurement_time(Duration::from_secs(5));
+
+        algorithms::registry_u64!(registry_generator, group, data);
+        fmt_generator!(group, "write_u64_fmt", data.iter());
+        itoa_generator!(group, "write_u64_itoa", data.iter());
+    }
+}
+
+criterion_group!(corpus_benches, corpus_u32, corpus_u64);
+criterion_main!(corpus_benches);
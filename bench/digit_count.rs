@@ -0,0 +1,48 @@
+#[macro_use]
+mod input;
+
+use core::time::Duration;
+
+use algorithms::DigitCount;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+// Default random data size.
+const COUNT: usize = 1000;
+
+macro_rules! bench {
+    ($fn:ident, $name:literal, $t:ty, $strategy:expr) => {
+        fn $fn(criterion: &mut Criterion) {
+            let mut group = criterion.benchmark_group($name);
+            group.measurement_time(Duration::from_secs(5));
+            let seed = input::seed();
+
+            let data = input::random_data::<$t>($name, $strategy, COUNT, seed);
+
+            group.bench_function(concat!(stringify!($t), "_digit_count"), |bench| {
+                bench.iter(|| {
+                    data.iter().for_each(|&x| {
+                        black_box(x.digit_count());
+                    })
+                })
+            });
+            group.bench_function(concat!(stringify!($t), "_ilog10"), |bench| {
+                bench.iter(|| {
+                    data.iter().for_each(|&x| {
+                        black_box(x.checked_ilog10().map_or(1, |y| y as usize + 1));
+                    })
+                })
+            });
+        }
+    };
+}
+
+bench!(uniform_u32, "digit_count:uniform:u32", u32, input::RandomGen::Uniform);
+bench!(simple_u32, "digit_count:simple:u32", u32, input::RandomGen::Simple);
+bench!(uniform_u64, "digit_count:uniform:u64", u64, input::RandomGen::Uniform);
+bench!(simple_u64, "digit_count:simple:u64", u64, input::RandomGen::Simple);
+bench!(uniform_u128, "digit_count:uniform:u128", u128, input::RandomGen::Uniform);
+bench!(simple_u128, "digit_count:simple:u128", u128, input::RandomGen::Simple);
+criterion_group!(u32_benches, uniform_u32, simple_u32);
+criterion_group!(u64_benches, uniform_u64, simple_u64);
+criterion_group!(u128_benches, uniform_u128, simple_u128);
+criterion_main!(u32_benches, u64_benches, u128_benches);
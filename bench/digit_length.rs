@@ -0,0 +1,46 @@
+#[macro_use]
+mod input;
+
+use core::time::Duration;
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+// Default random data size.
+const COUNT: usize = 1000;
+
+// Each group has values with exactly `n` digits, so the results can be
+// plotted per digit count, which shows where each algorithm's branches are.
+fn digit_length_u32(criterion: &mut Criterion) {
+    let seed = input::seed();
+    for digits in 1..=input::max_digits(u32::MAX as u128) {
+        let name = format!("digit_length:u32:{:02}", digits);
+        let mut group = criterion.benchmark_group(&name);
+        group.measurement_time(Duration::from_secs(5));
+
+        let strategy = input::RandomGen::DigitLength(digits);
+        let data = input::random_data::<u32>(&name, strategy, COUNT, seed);
+
+        algorithms::registry_u32!(registry_generator, group, data);
+        fmt_generator!(group, "write_u32_fmt", data.iter());
+        itoa_generator!(group, "write_u32_itoa", data.iter());
+    }
+}
+
+fn digit_length_u64(criterion: &mut Criterion) {
+    let seed = input::seed();
+    for digits in 1..=input::max_digits(u64::MAX as u128) {
+        let name = format!("digit_length:u64:{:02}", digits);
+        let mut group = criterion.benchmark_group(&name);
+        group.measurement_time(Duration::from_secs(5));
+
+        let strategy = input::RandomGen::DigitLength(digits);
+        let data = input::random_data::<u64>(&name, strategy, COUNT, seed);
+
+        algorithms::registry_u64!(registry_generator, group, data);
+        fmt_generator!(group, "write_u64_fmt", data.iter());
+        itoa_generator!(group, "write_u64_itoa", data.iter());
+    }
+}
+
+criterion_group!(digit_length_benches, digit_length_u32, digit_length_u64);
+criterion_main!(digit_length_benches);
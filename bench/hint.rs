@@ -0,0 +1,55 @@
+#[macro_use]
+mod input;
+
+use core::time::Duration;
+
+use algorithms::{LikelyLarge, LikelySmall, NoHint};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+// Default random data size.
+const COUNT: usize = 1000;
+
+// Bench a hinted writer, named like `write_u64_jeaiii64_better_v4_hint_LikelySmall_c`.
+macro_rules! hint_generator {
+    ($group:ident, $func:ident, $hint:ident, $iter:expr) => {{
+        use crate::input::BUFFER_SIZE;
+        let mut buffer: [u8; BUFFER_SIZE] = [b'0'; BUFFER_SIZE];
+        let name = concat!("write_u64_", stringify!($func), "_", stringify!($hint), "_c");
+        $group.bench_function(name, |bench| {
+            bench.iter(|| {
+                $iter.for_each(|&x| {
+                    black_box(algorithms::$func::<$hint, true>(x, &mut buffer));
+                })
+            })
+        });
+    }};
+}
+
+// Every hint is run on every strategy, so each group shows both the gain
+// of the matching hint, and the cost of the mismatched one.
+macro_rules! bench {
+    ($fn:ident, $name:literal, $strategy:expr) => {
+        fn $fn(criterion: &mut Criterion) {
+            let mut group = criterion.benchmark_group($name);
+            group.measurement_time(Duration::from_secs(5));
+            let seed = input::seed();
+            let data = input::random_data::<u64>($name, $strategy, COUNT, seed);
+
+            hint_generator!(group, jeaiii64_better_v4_hint, NoHint, data.iter());
+            hint_generator!(group, jeaiii64_better_v4_hint, LikelySmall, data.iter());
+            hint_generator!(group, jeaiii64_better_v4_hint, LikelyLarge, data.iter());
+            hint_generator!(group, jeaiii64_better_v5_hint, NoHint, data.iter());
+            hint_generator!(group, jeaiii64_better_v5_hint, LikelySmall, data.iter());
+            hint_generator!(group, jeaiii64_better_v5_hint, LikelyLarge, data.iter());
+        }
+    };
+}
+
+// `LikelySmall` matches `simple`, and `LikelyLarge` matches `large` and the
+// timestamp-sized `digits_19`.
+bench!(simple, "hint:simple", input::RandomGen::Simple);
+bench!(large, "hint:large", input::RandomGen::Large);
+bench!(digits_19, "hint:digits_19", input::RandomGen::DigitLength(19));
+bench!(log_uniform, "hint:log_uniform", input::RandomGen::LogUniform);
+criterion_group!(hint_benches, simple, large, digits_19, log_uniform);
+criterion_main!(hint_benches);
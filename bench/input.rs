@@ -11,6 +11,35 @@ use fastrand::Rng;
 
 pub(crate) const BUFFER_SIZE: usize = 144;
 
+// Larger than the L3 cache of most desktop and server CPUs, so the lookup
+// tables are evicted from every cache level, and not just from the L2.
+pub(crate) const EVICT_SIZE: usize = 64 * 1024 * 1024;
+
+// CACHE
+
+/// Set the size of the eviction buffer in MiB, for example,
+/// `BENCH_EVICT_MB=512 cargo bench --bench cache` for CPUs with a larger L3.
+pub const EVICT_VAR: &str = "BENCH_EVICT_MB";
+
+/// Get the size of the eviction buffer, from `BENCH_EVICT_MB` if set.
+#[inline]
+pub fn evict_size() -> usize {
+    match std::env::var(EVICT_VAR) {
+        Ok(size) => size.trim().parse::<usize>().expect("BENCH_EVICT_MB must be a usize") << 20,
+        Err(_) => EVICT_SIZE,
+    }
+}
+
+/// Evict the lookup tables from the cache, by touching every cache line
+/// of a buffer larger than the last-level cache.
+#[inline(never)]
+pub fn evict_cache(buffer: &mut [u8]) {
+    for index in (0..buffer.len()).step_by(64) {
+        buffer[index] = buffer[index].wrapping_add(1);
+    }
+    core::hint::black_box(buffer);
+}
+
 // PATH
 
 /// Return the `target` directory path.
@@ -80,10 +109,78 @@ pub fn read_csv(name: &str) -> Vec<String> {
         .collect()
 }
 
+/// Get the names of the corpus files to benchmark, with a `.txt` or `.csv`
+/// extension in the data directory. Subdirectories, like the dumped random
+/// data, are not included.
+///
+/// If the environment variable `var` is set, only the comma-separated file
+/// names it contains are used.
+#[inline]
+pub fn corpus_files(var: &str) -> Vec<String> {
+    if let Ok(names) = std::env::var(var) {
+        return names
+            .split(',')
+            .map(|x| x.trim().to_string())
+            .filter(|x| !x.is_empty())
+            .collect();
+    }
+
+    let mut path = bench_dir();
+    path.push("data");
+    let mut names: Vec<String> = std::fs::read_dir(path)
+        .unwrap()
+        .map(|entry| entry.unwrap())
+        .filter(|entry| entry.file_type().unwrap().is_file())
+        .map(|entry| entry.file_name().into_string().unwrap())
+        .filter(|name| name.ends_with(".txt") || name.ends_with(".csv"))
+        .collect();
+    names.sort();
+    names
+}
+
+// If a token is an integer, with an optional sign, so it should parse.
+#[inline]
+fn is_integer(token: &str) -> bool {
+    let digits = token.strip_prefix(&['-', '+'][..]).unwrap_or(token);
+    !digits.is_empty() && digits.bytes().all(|x| x.is_ascii_digit())
+}
+
+/// Read the integers from a corpus file, skipping any non-numeric values,
+/// such as headers. CSV files may contain multiple values per line.
+///
+/// Integers that do not fit in `T`, like a `u64` read as a `u32`, are also
+/// skipped, but counted and reported, so no data is silently dropped.
+#[inline]
+pub fn read_corpus<T: FromStr>(name: &str) -> Vec<T> {
+    let values = if name.ends_with(".csv") {
+        read_csv(name)
+    } else {
+        read_lines(name)
+    };
+    let mut out_of_range = 0;
+    let data: Vec<T> = values
+        .iter()
+        .map(|x| x.trim())
+        .filter(|x| is_integer(x))
+        .filter_map(|x| {
+            let value = x.parse::<T>().ok();
+            out_of_range += value.is_none() as usize;
+            value
+        })
+        .collect();
+    if out_of_range != 0 {
+        let type_name = std::any::type_name::<T>();
+        eprintln!("{}: skipped {} values out of range for {}", name, out_of_range, type_name);
+    }
+    data
+}
+
 /// Parse JSON data as a given type.
+///
+/// - `fn` - The name to register the parsed data as
 macro_rules! json_data {
-    ($t:ty, $file:literal) => {
-        fn json_data() -> &'static $t {
+    ($fn:ident, $t:ty, $file:literal) => {
+        fn $fn() -> &'static $t {
             use lazy_static::lazy_static;
             lazy_static! {
                 static ref DATA: $t = input::read_json($file);
@@ -91,6 +188,10 @@ macro_rules! json_data {
             &*DATA
         }
     };
+
+    ($t:ty, $file:literal) => {
+        json_data!(json_data, $t, $file);
+    };
 }
 
 /// Generate an array of values as static data
@@ -134,6 +235,24 @@ macro_rules! static_data {
     )*);
 }
 
+/// Collect a field from every record as static data.
+///
+/// - `fn` - The name to register the static data as
+/// - `cb` - The function to fetch the data
+/// - `f1` - The field containing the records
+/// - `f2` - The field within each record to fetch
+macro_rules! record_data {
+    ($($fn:ident $cb:ident $f1:ident $f2:ident $t:tt ; )*) => ($(
+        fn $fn() -> &'static [$t] {
+            use lazy_static::lazy_static;
+            lazy_static! {
+                static ref DATA: Vec<$t> = $cb().$f1.iter().map(|x| x.$f2).collect();
+            }
+            &*DATA
+        }
+    )*);
+}
+
 // RANDOM
 
 #[derive(Copy, Clone, PartialEq, Eq)]
@@ -148,6 +267,10 @@ pub enum RandomGen {
     LargeSigned,
     SafeInt,
     LargeSafeInt,
+    // Values with exactly `n` digits.
+    DigitLength(u32),
+    // A uniform digit count, and then a uniform value with that many digits.
+    LogUniform,
 
     // Floats
     OneOverRand32,
@@ -171,6 +294,34 @@ pub trait IntegerRng: NumberRng {
     fn large_signed(rng: &mut Rng) -> String;
     fn safe_int(rng: &mut Rng) -> String;
     fn large_safe_int(rng: &mut Rng) -> String;
+    fn digit_length(rng: &mut Rng, digits: u32) -> String;
+    fn log_uniform(rng: &mut Rng) -> String;
+}
+
+/// Get the range of values with exactly `digits` digits, up to `max`.
+#[inline]
+pub fn digit_range(digits: u32, max: u128) -> (u128, u128) {
+    let min = if digits <= 1 { 0 } else { 10u128.pow(digits - 1) };
+    assert!(digits > 0 && min <= max, "no values with {} digits", digits);
+    let upper = 10u128.checked_pow(digits).map_or(u128::MAX, |x| x - 1);
+    (min, upper.min(max))
+}
+
+/// Get the number of digits in `max`.
+#[inline]
+pub fn max_digits(max: u128) -> u32 {
+    max.to_string().len() as u32
+}
+
+pub trait FloatRng: NumberRng {
+    fn uniform(rng: &mut Rng) -> String;
+    fn one_over_rand32(rng: &mut Rng) -> String;
+    fn simple_uniform32(rng: &mut Rng) -> String;
+    fn simple_int32(rng: &mut Rng) -> String;
+    fn int_e_int(rng: &mut Rng) -> String;
+    fn simple_int64(rng: &mut Rng) -> String;
+    fn big_int_dot_int(rng: &mut Rng) -> String;
+    fn big_ints(rng: &mut Rng) -> String;
 }
 
 /// Generate an unsigned, random range for testing.
@@ -191,6 +342,8 @@ macro_rules! unsigned_rng {
                     RandomGen::LargeSigned => Self::large_signed(rng),
                     RandomGen::SafeInt => Self::safe_int(rng),
                     RandomGen::LargeSafeInt => Self::large_safe_int(rng),
+                    RandomGen::DigitLength(digits) => Self::digit_length(rng, digits),
+                    RandomGen::LogUniform => Self::log_uniform(rng),
                     _ => unimplemented!(),
                 }
             }
@@ -245,6 +398,18 @@ macro_rules! unsigned_rng {
                     rng.u64(MIN..MAX).to_string()
                 }
             }
+
+            #[inline]
+            fn digit_length(rng: &mut Rng, digits: u32) -> String {
+                let (min, max) = digit_range(digits, $t::MAX as u128);
+                rng.u128(min..=max).to_string()
+            }
+
+            #[inline]
+            fn log_uniform(rng: &mut Rng) -> String {
+                let digits = rng.u32(1..=max_digits($t::MAX as u128));
+                Self::digit_length(rng, digits)
+            }
         }
     )*);
 }
@@ -274,6 +439,8 @@ macro_rules! signed_rng {
                     RandomGen::SimpleSigned => Self::simple_signed(rng),
                     RandomGen::Large => Self::large(rng),
                     RandomGen::LargeSigned => Self::large_signed(rng),
+                    RandomGen::DigitLength(digits) => Self::digit_length(rng, digits),
+                    RandomGen::LogUniform => Self::log_uniform(rng),
                     _ => unimplemented!(),
                 }
             }
@@ -333,6 +500,19 @@ macro_rules! signed_rng {
                     value.to_string()
                 }
             }
+
+            // Only generates positive values with that many digits.
+            #[inline]
+            fn digit_length(rng: &mut Rng, digits: u32) -> String {
+                let (min, max) = digit_range(digits, $t::MAX as u128);
+                rng.u128(min..=max).to_string()
+            }
+
+            #[inline]
+            fn log_uniform(rng: &mut Rng) -> String {
+                let digits = rng.u32(1..=max_digits($t::MAX as u128));
+                Self::digit_length(rng, digits)
+            }
         }
     )*);
 }
@@ -353,6 +533,78 @@ signed_rng! {
     i128 0 1000 5316911983139663491615228241121378304 170141183460469231731687303715884105727 -1000 1000 -170141183460469231731687303715884105727 -5316911983139663491615228241121378304 ;
 }
 
+/// Generate a float, random range for testing.
+///
+/// These match the strategies from fast-float-rust: the `IntEInt`,
+/// `BigIntDotInt` and `BigInts` strategies are not round-tripped through
+/// the float type, so they can produce non-canonical float strings.
+macro_rules! float_rng {
+    ($($t:ident ; )*) => ($(
+        impl NumberRng for $t {
+            fn gen(strategy: RandomGen, rng: &mut Rng) -> String {
+                match strategy {
+                    RandomGen::Uniform => Self::uniform(rng),
+                    RandomGen::OneOverRand32 => Self::one_over_rand32(rng),
+                    RandomGen::SimpleUniform32 => Self::simple_uniform32(rng),
+                    RandomGen::SimpleInt32 => Self::simple_int32(rng),
+                    RandomGen::IntEInt => Self::int_e_int(rng),
+                    RandomGen::SimpleInt64 => Self::simple_int64(rng),
+                    RandomGen::BigIntDotInt => Self::big_int_dot_int(rng),
+                    RandomGen::BigInts => Self::big_ints(rng),
+                    _ => unimplemented!(),
+                }
+            }
+        }
+
+        impl FloatRng for $t {
+            #[inline]
+            fn uniform(rng: &mut Rng) -> String {
+                (rng.f64() as $t).to_string()
+            }
+
+            #[inline]
+            fn one_over_rand32(rng: &mut Rng) -> String {
+                ((1.0 / rng.u32(1..) as f64) as $t).to_string()
+            }
+
+            #[inline]
+            fn simple_uniform32(rng: &mut Rng) -> String {
+                ((rng.u32(..) as f64 / u32::MAX as f64) as $t).to_string()
+            }
+
+            #[inline]
+            fn simple_int32(rng: &mut Rng) -> String {
+                (rng.u32(..) as $t).to_string()
+            }
+
+            #[inline]
+            fn int_e_int(rng: &mut Rng) -> String {
+                format!("{}e{}", rng.u32(..), rng.u32(..99))
+            }
+
+            #[inline]
+            fn simple_int64(rng: &mut Rng) -> String {
+                (rng.u64(..) as $t).to_string()
+            }
+
+            #[inline]
+            fn big_int_dot_int(rng: &mut Rng) -> String {
+                format!("{}.{}", rng.u32(..), rng.u32(..))
+            }
+
+            #[inline]
+            fn big_ints(rng: &mut Rng) -> String {
+                format!("{}{}{}", rng.u64(..), rng.u64(..), rng.u64(..))
+            }
+        }
+    )*);
+}
+
+float_rng! {
+    f32 ;
+    f64 ;
+}
+
 // Generate a static array of random values.
 #[inline]
 pub fn string_from_random<T>(strategy: RandomGen, count: usize, seed: u64) -> Vec<String>
@@ -380,6 +632,126 @@ where
         .collect()
 }
 
+// SEED
+
+/// Set the seed for all random data, for example, `BENCH_SEED=1234 cargo bench`.
+pub const SEED_VAR: &str = "BENCH_SEED";
+
+/// Dump all random data to the `data/dumps` directory, for example,
+/// `BENCH_DUMP=1 cargo bench`. The dumps are not used by the corpus benches
+/// by default, but a run can be replayed with, for example,
+/// `INTEGER_CORPUS=dumps/NAME.txt`.
+pub const DUMP_VAR: &str = "BENCH_DUMP";
+
+/// The subdirectory of the data directory for the dumped random data.
+pub const DUMP_DIR: &str = "dumps";
+
+/// Get the seed for the random data, from `BENCH_SEED` if set.
+#[inline]
+pub fn seed() -> u64 {
+    match std::env::var(SEED_VAR) {
+        Ok(seed) => seed.trim().parse().expect("BENCH_SEED must be a u64"),
+        Err(_) => fastrand::u64(..),
+    }
+}
+
+/// Convert a group name to the directory name used by criterion.
+#[inline]
+pub fn report_name(group: &str) -> String {
+    group.replace(&['?', '"', '/', '\\', '*', '<', '>', ':', '|', '^'][..], "_")
+}
+
+/// Generate random values for a bench group, logging the seed to the
+/// criterion report for the group, and dumping the data if requested.
+#[inline]
+pub fn random_data<T>(group: &str, strategy: RandomGen, count: usize, seed: u64) -> Vec<T>
+where
+    T: NumberRng + FromStr,
+    <T as FromStr>::Err: Debug,
+{
+    let strings = string_from_random::<T>(strategy, count, seed);
+    let type_name = std::any::type_name::<T>();
+    let name = report_name(group);
+    eprintln!("{}: {} data with {}={}", group, type_name, SEED_VAR, seed);
+
+    let mut path = target_dir();
+    path.push("criterion");
+    path.push(&name);
+    std::fs::create_dir_all(&path).unwrap();
+    path.push(format!("seed-{}.txt", type_name));
+    std::fs::write(path, format!("{}\n", seed)).unwrap();
+
+    if std::env::var_os(DUMP_VAR).is_some() {
+        let mut path = bench_dir();
+        path.push("data");
+        path.push(DUMP_DIR);
+        std::fs::create_dir_all(&path).unwrap();
+        path.push(format!("{}-{}-{}.txt", name, type_name, seed));
+        std::fs::write(path, strings.join("\n") + "\n").unwrap();
+    }
+
+    strings.iter().map(|x| x.parse::<T>().unwrap()).collect()
+}
+
+// ORDER
+
+/// The order to feed values to a writer, to measure branch prediction.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Order {
+    // Sorted by value, so the digit count only changes a few times.
+    Sorted,
+    // Grouped by digit count, but shuffled within each group.
+    Grouped,
+    // Alternating between the shortest and longest remaining values.
+    Alternating,
+    // Fully shuffled, so the digit count is unpredictable.
+    Shuffled,
+}
+
+impl Order {
+    /// Get the name of the order, for the bench group.
+    pub const fn name(self) -> &'static str {
+        match self {
+            Order::Sorted => "sorted",
+            Order::Grouped => "grouped",
+            Order::Alternating => "alternating",
+            Order::Shuffled => "shuffled",
+        }
+    }
+}
+
+/// Reorder the values, keeping the same data for each order.
+#[inline]
+pub fn reorder<T>(data: &[T], order: Order, seed: u64) -> Vec<T>
+where
+    T: algorithms::DigitCount + Copy + Ord,
+{
+    let mut rng = Rng::with_seed(seed);
+    let mut vec = data.to_vec();
+    match order {
+        Order::Sorted => vec.sort_unstable(),
+        Order::Grouped => {
+            rng.shuffle(&mut vec);
+            vec.sort_by_key(|x| x.digit_count());
+        },
+        Order::Alternating => {
+            rng.shuffle(&mut vec);
+            vec.sort_by_key(|x| x.digit_count());
+            let (short, long) = vec.split_at(vec.len() / 2);
+            let mut alternating = Vec::with_capacity(vec.len());
+            let mut long = long.iter().rev();
+            for &x in short {
+                alternating.push(x);
+                alternating.extend(long.next());
+            }
+            alternating.extend(long);
+            vec = alternating;
+        },
+        Order::Shuffled => rng.shuffle(&mut vec),
+    }
+    vec
+}
+
 // GENERATORS
 
 // For all of these:
@@ -414,6 +786,34 @@ macro_rules! checked_generator {
     }};
 }
 
+// Evict the cache before every iteration, so the lookup tables start cold.
+// Only the formatting is timed, and not the eviction.
+macro_rules! cold_generator {
+    ($group:ident, $name:expr, $iter:expr, $func:ident, $checked:literal, $batch:expr) => {{
+        use crate::input::{evict_cache, evict_size, BUFFER_SIZE};
+        let mut buffer: [u8; BUFFER_SIZE] = [b'0'; BUFFER_SIZE];
+        let mut evict = vec![0u8; evict_size()];
+        let values: Vec<_> = $iter.copied().collect();
+        let mut batches = values.chunks($batch).cycle();
+        $group.bench_function($name, |bench| {
+            // Each iteration writes a single batch, after evicting the cache.
+            bench.iter_custom(|iters| {
+                let mut elapsed = std::time::Duration::ZERO;
+                for _ in 0..iters {
+                    let batch = batches.next().unwrap();
+                    evict_cache(&mut evict);
+                    let start = std::time::Instant::now();
+                    for &x in batch {
+                        black_box(algorithms::$func::<$checked>(x, &mut buffer));
+                    }
+                    elapsed += start.elapsed();
+                }
+                elapsed
+            })
+        });
+    }};
+}
+
 macro_rules! fmt_generator {
     ($group:ident, $name:expr, $iter:expr) => {{
         use std::io::Write;
@@ -520,3 +920,62 @@ macro_rules! write_u64_generator {
         }
     }};
 }
+
+macro_rules! write_i64_generator {
+    ($group:ident, $meth:ident, $iter:expr, $checked:expr) => {{
+        if $checked {
+            checked_generator!(
+                $group,
+                concat!("write_i64_", stringify!($meth), "_c"),
+                $iter,
+                $meth,
+                true
+            );
+        } else {
+            checked_generator!(
+                $group,
+                concat!("write_i64_", stringify!($meth), "_u"),
+                $iter,
+                $meth,
+                false
+            );
+        }
+    }};
+}
+
+// Bench every registered algorithm, as a callback for the `registry_*!`
+// macros, for example, `algorithms::registry_u64!(registry_generator, group, data)`.
+macro_rules! registry_generator {
+    (
+        $t:ident, $group:ident, $data:ident ;
+        $(
+            $name:ident,
+            $checked:ident,
+            $unchecked:ident,
+            $bench:literal,
+            $width:expr,
+            $min:expr,
+            $max:expr ;
+        )*
+    ) => {{
+        $(
+            if $bench {
+                checked_generator!(
+                    $group,
+                    concat!("write_", stringify!($t), "_", stringify!($name), "_c"),
+                    $data.iter(),
+                    $name,
+                    true
+                );
+            } else {
+                checked_generator!(
+                    $group,
+                    concat!("write_", stringify!($t), "_", stringify!($name), "_u"),
+                    $data.iter(),
+                    $name,
+                    false
+                );
+            }
+        )*
+    }};
+}
@@ -0,0 +1,55 @@
+#[macro_use]
+mod input;
+
+use core::time::Duration;
+use std::io::{self, BufWriter, Write};
+
+use algorithms::DecimalWriter;
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+// Default random data size.
+const COUNT: usize = 1000;
+
+// Write each value on a separate line to an `io::sink()`, which measures the
+// formatting and buffering, and not the underlying IO.
+macro_rules! bench {
+    ($fn:ident, $name:literal, $t:ty, $strategy:expr) => {
+        fn $fn(criterion: &mut Criterion) {
+            let mut group = criterion.benchmark_group($name);
+            group.measurement_time(Duration::from_secs(5));
+            let seed = input::seed();
+
+            let data = input::random_data::<$t>($name, $strategy, COUNT, seed);
+
+            group.bench_function(concat!("write_", stringify!($t), "_decimal_writer"), |bench| {
+                let mut writer = DecimalWriter::new(io::sink());
+                bench.iter(|| {
+                    data.iter().for_each(|&x| {
+                        black_box(writer.write_int_sep(x, b'\n').unwrap());
+                    });
+                    writer.flush().unwrap();
+                })
+            });
+            group.bench_function(concat!("write_", stringify!($t), "_write"), |bench| {
+                let mut writer = BufWriter::new(io::sink());
+                bench.iter(|| {
+                    data.iter().for_each(|&x| {
+                        black_box(writeln!(writer, "{}", x).unwrap());
+                    });
+                    writer.flush().unwrap();
+                })
+            });
+        }
+    };
+}
+
+bench!(uniform_u32, "io:uniform:u32", u32, input::RandomGen::Uniform);
+bench!(simple_u32, "io:simple:u32", u32, input::RandomGen::Simple);
+bench!(uniform_u64, "io:uniform:u64", u64, input::RandomGen::Uniform);
+bench!(simple_u64, "io:simple:u64", u64, input::RandomGen::Simple);
+bench!(simple_i64, "io:simple:i64", i64, input::RandomGen::SimpleSigned);
+bench!(large_i64, "io:large:i64", i64, input::RandomGen::LargeSigned);
+criterion_group!(u32_benches, uniform_u32, simple_u32);
+criterion_group!(u64_benches, uniform_u64, simple_u64);
+criterion_group!(i64_benches, simple_i64, large_i64);
+criterion_main!(u32_benches, u64_benches, i64_benches);
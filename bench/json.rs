@@ -0,0 +1,193 @@
+#[macro_use]
+mod input;
+
+use core::time::Duration;
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use serde::{Deserialize, Serialize};
+
+// A web service request log, from `data/events.json`.
+#[derive(Deserialize, Serialize)]
+struct Event {
+    id: u64,
+    user_id: u32,
+    timestamp: u64,
+    duration_us: u32,
+    status: u16,
+    port: u16,
+    retries: u8,
+    skew_ms: i64,
+}
+
+#[derive(Deserialize, Serialize)]
+struct Events {
+    events: Vec<Event>,
+}
+
+// A metrics time series, from `data/metrics.json`.
+#[derive(Deserialize, Serialize)]
+struct Metric {
+    timestamp: u64,
+    requests_total: u64,
+    bytes_sent: u64,
+    active_connections: u32,
+    queue_depth: u32,
+    cpu_percent: u8,
+    memory_bytes: u64,
+    delta: i64,
+}
+
+#[derive(Deserialize, Serialize)]
+struct Metrics {
+    metrics: Vec<Metric>,
+}
+
+json_data!(events_data, Events, "events.json");
+json_data!(metrics_data, Metrics, "metrics.json");
+
+record_data! {
+    events_id events_data events id u64 ;
+    events_user_id events_data events user_id u32 ;
+    events_timestamp events_data events timestamp u64 ;
+    events_duration_us events_data events duration_us u32 ;
+    events_status events_data events status u16 ;
+    events_port events_data events port u16 ;
+    events_retries events_data events retries u8 ;
+    events_skew_ms events_data events skew_ms i64 ;
+    metrics_timestamp metrics_data metrics timestamp u64 ;
+    metrics_requests_total metrics_data metrics requests_total u64 ;
+    metrics_bytes_sent metrics_data metrics bytes_sent u64 ;
+    metrics_active_connections metrics_data metrics active_connections u32 ;
+    metrics_queue_depth metrics_data metrics queue_depth u32 ;
+    metrics_cpu_percent metrics_data metrics cpu_percent u8 ;
+    metrics_memory_bytes metrics_data metrics memory_bytes u64 ;
+    metrics_delta metrics_data metrics delta i64 ;
+}
+
+// Bench every registered algorithm for the type, and the standard library.
+macro_rules! bench_registry {
+    ($fn:ident, $name:literal, $data:ident, $t:ident, $registry:ident) => {
+        fn $fn(criterion: &mut Criterion) {
+            let mut group = criterion.benchmark_group($name);
+            group.measurement_time(Duration::from_secs(5));
+            let data = $data();
+
+            algorithms::$registry!(registry_generator, group, data);
+            fmt_generator!(group, concat!("write_", stringify!($t), "_fmt"), data.iter());
+            itoa_generator!(group, concat!("write_", stringify!($t), "_itoa"), data.iter());
+        }
+    };
+}
+
+macro_rules! bench_u8 {
+    ($fn:ident, $name:literal, $data:ident) => {
+        bench_registry!($fn, $name, $data, u8, registry_u8);
+    };
+}
+
+macro_rules! bench_u16 {
+    ($fn:ident, $name:literal, $data:ident) => {
+        bench_registry!($fn, $name, $data, u16, registry_u16);
+    };
+}
+
+macro_rules! bench_u32 {
+    ($fn:ident, $name:literal, $data:ident) => {
+        bench_registry!($fn, $name, $data, u32, registry_u32);
+    };
+}
+
+macro_rules! bench_u64 {
+    ($fn:ident, $name:literal, $data:ident) => {
+        bench_registry!($fn, $name, $data, u64, registry_u64);
+    };
+}
+
+macro_rules! bench_i64 {
+    ($fn:ident, $name:literal, $data:ident) => {
+        fn $fn(criterion: &mut Criterion) {
+            let mut group = criterion.benchmark_group($name);
+            group.measurement_time(Duration::from_secs(5));
+            let data = $data();
+
+            write_i64_generator!(group, alexandrescu_i64, data.iter(), false);
+            let mut buffer = [b'0'; input::BUFFER_SIZE];
+            group.bench_function("write_i64_naive_radix_i64_u", |bench| {
+                bench.iter(|| {
+                    data.iter().for_each(|&x| {
+                        black_box(algorithms::naive_radix_i64::<false>(x, 10, &mut buffer));
+                    })
+                })
+            });
+            fmt_generator!(group, "write_i64_fmt", data.iter());
+            itoa_generator!(group, "write_i64_itoa", data.iter());
+        }
+    };
+}
+
+// Serialize the entire document, to measure the difference end to end.
+macro_rules! bench_serde {
+    ($fn:ident, $name:literal, $data:ident) => {
+        fn $fn(criterion: &mut Criterion) {
+            let mut group = criterion.benchmark_group($name);
+            group.measurement_time(Duration::from_secs(5));
+            let data = $data();
+
+            group.bench_function("serde_json", |bench| {
+                bench.iter(|| black_box(serde_json::to_vec(data).unwrap()))
+            });
+            group.bench_function("json_formatter", |bench| {
+                bench.iter(|| black_box(algorithms::to_json_vec(data).unwrap()))
+            });
+        }
+    };
+}
+
+bench_u64!(events_id_bench, "json:events:id", events_id);
+bench_u32!(events_user_id_bench, "json:events:user_id", events_user_id);
+bench_u64!(events_timestamp_bench, "json:events:timestamp", events_timestamp);
+bench_u32!(events_duration_us_bench, "json:events:duration_us", events_duration_us);
+bench_u16!(events_status_bench, "json:events:status", events_status);
+bench_u16!(events_port_bench, "json:events:port", events_port);
+bench_u8!(events_retries_bench, "json:events:retries", events_retries);
+bench_i64!(events_skew_ms_bench, "json:events:skew_ms", events_skew_ms);
+bench_serde!(events_serde_bench, "json:events:serde", events_data);
+bench_u64!(metrics_timestamp_bench, "json:metrics:timestamp", metrics_timestamp);
+bench_u64!(metrics_requests_total_bench, "json:metrics:requests_total", metrics_requests_total);
+bench_u64!(metrics_bytes_sent_bench, "json:metrics:bytes_sent", metrics_bytes_sent);
+bench_u32!(
+    metrics_active_connections_bench,
+    "json:metrics:active_connections",
+    metrics_active_connections
+);
+bench_u32!(metrics_queue_depth_bench, "json:metrics:queue_depth", metrics_queue_depth);
+bench_u8!(metrics_cpu_percent_bench, "json:metrics:cpu_percent", metrics_cpu_percent);
+bench_u64!(metrics_memory_bytes_bench, "json:metrics:memory_bytes", metrics_memory_bytes);
+bench_i64!(metrics_delta_bench, "json:metrics:delta", metrics_delta);
+bench_serde!(metrics_serde_bench, "json:metrics:serde", metrics_data);
+
+criterion_group!(
+    events_benches,
+    events_id_bench,
+    events_user_id_bench,
+    events_timestamp_bench,
+    events_duration_us_bench,
+    events_status_bench,
+    events_port_bench,
+    events_retries_bench,
+    events_skew_ms_bench,
+    events_serde_bench
+);
+criterion_group!(
+    metrics_benches,
+    metrics_timestamp_bench,
+    metrics_requests_total_bench,
+    metrics_bytes_sent_bench,
+    metrics_active_connections_bench,
+    metrics_queue_depth_bench,
+    metrics_cpu_percent_bench,
+    metrics_memory_bytes_bench,
+    metrics_delta_bench,
+    metrics_serde_bench
+);
+criterion_main!(events_benches, metrics_benches);
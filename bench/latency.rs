@@ -0,0 +1,221 @@
+//! Measure the latency of individual calls with the timestamp counter.
+//!
+//! Criterion only reports the mean time for a batch of values, which hides
+//! the tail latency. This times every call with `rdtsc` and `rdtscp`, fenced
+//! so the call cannot be reordered around the reads, and reports the p50,
+//! p90 and p99 latency in cycles for each algorithm and digit length. The
+//! overhead of the timing itself is calibrated with an empty call, and
+//! subtracted from every sample. This is only supported on x86_64, for
+//! example:
+//!
+//! ```bash
+//! cargo run --release --bin latency -- --type u64 --algorithm jeaiii64_better_v4
+//! ```
+//!
+//! The options are:
+//! - `--type NAME`: Only measure `u32` or `u64`, by default, both.
+//! - `--algorithm NAME`: Only measure a single algorithm.
+//! - `--samples N`: The calls timed for each digit length, by default, 10000.
+//!
+//! The seed can be set with `BENCH_SEED`, like the benches. The counter
+//! ticks at a constant rate on modern CPUs, which may differ from the core
+//! clock, so compare results from the same machine.
+
+#[macro_use]
+mod input;
+
+use std::process;
+
+// The default number of timed calls for each digit length.
+const SAMPLES: usize = 10000;
+
+// The number of timed empty calls, to calibrate the overhead.
+const CALIBRATION_SAMPLES: usize = 100000;
+
+#[cfg_attr(not(target_arch = "x86_64"), allow(dead_code))]
+struct Options {
+    int_type: Option<String>,
+    algorithm: Option<String>,
+    samples: usize,
+}
+
+#[cfg_attr(not(target_arch = "x86_64"), allow(dead_code))]
+impl Options {
+    fn measure_type(&self, int_type: &str) -> bool {
+        self.int_type.as_deref().is_none_or(|x| x == int_type)
+    }
+
+    fn measure_algorithm(&self, name: &str) -> bool {
+        self.algorithm.as_deref().is_none_or(|x| x == name)
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+mod tsc {
+    use core::arch::x86_64::{__rdtscp, _mm_lfence, _rdtsc};
+
+    // Time a call in cycles. The fences stop earlier instructions from
+    // running after the first read, and later ones from starting before the
+    // second read, while `rdtscp` waits for the call itself to finish.
+    #[inline(always)]
+    pub fn cycles<F: FnMut()>(mut call: F) -> u64 {
+        let mut aux = 0;
+        unsafe {
+            _mm_lfence();
+            let start = _rdtsc();
+            _mm_lfence();
+            call();
+            let end = __rdtscp(&mut aux);
+            _mm_lfence();
+            end.wrapping_sub(start)
+        }
+    }
+}
+
+// Get a percentile of the sorted samples, with the nearest rank.
+#[cfg(target_arch = "x86_64")]
+fn percentile(sorted: &[u64], percent: usize) -> u64 {
+    let rank = (percent * sorted.len()).div_ceil(100);
+    sorted[rank.saturating_sub(1)]
+}
+
+// Get the median cycles for an empty call, which is the harness overhead.
+#[cfg(target_arch = "x86_64")]
+fn calibrate() -> u64 {
+    let mut samples: Vec<u64> = (0..CALIBRATION_SAMPLES)
+        .map(|_| tsc::cycles(|| _ = core::hint::black_box(0u64)))
+        .collect();
+    samples.sort_unstable();
+    percentile(&samples, 50)
+}
+
+// Time each call over the data, and print a row with the percentiles.
+#[cfg(target_arch = "x86_64")]
+fn measure<T: Copy, F: Fn(T, &mut [u8]) -> usize>(
+    name: &str,
+    digits: u32,
+    data: &[T],
+    overhead: u64,
+    write: F,
+) {
+    use core::hint::black_box;
+
+    let mut buffer = [b'0'; input::BUFFER_SIZE];
+    // Warm up the caches and the branch predictor.
+    data.iter().for_each(|&x| _ = black_box(write(black_box(x), &mut buffer)));
+    let mut samples: Vec<u64> = data
+        .iter()
+        .map(|&x| {
+            let x = black_box(x);
+            let cycles = tsc::cycles(|| _ = black_box(write(x, &mut buffer)));
+            cycles.saturating_sub(overhead)
+        })
+        .collect();
+    samples.sort_unstable();
+    println!(
+        "| {} | {} | {} | {} | {} |",
+        name,
+        digits,
+        percentile(&samples, 50),
+        percentile(&samples, 90),
+        percentile(&samples, 99)
+    );
+}
+
+// Measure every registered algorithm for each digit length, with the
+// `CHECKED` mode used in the benches. Fixed-width algorithms write every
+// value with the same number of digits, so they are measured too.
+#[cfg(target_arch = "x86_64")]
+macro_rules! latency {
+    (
+        $t:ident, $options:ident, $datasets:ident, $overhead:ident ;
+        $(
+            $name:ident,
+            $checked:ident,
+            $unchecked:ident,
+            $bench:literal,
+            $width:expr,
+            $min:expr,
+            $max:expr ;
+        )*
+    ) => {{
+        $(
+            if $options.measure_algorithm(stringify!($name)) {
+                let write = |x, buffer: &mut [u8]| algorithms::$name::<$bench>(x, buffer).len();
+                for (digits, data) in $datasets.iter() {
+                    measure(stringify!($name), *digits, data, $overhead, write);
+                }
+            }
+        )*
+    }};
+}
+
+// Print the latency table for a type.
+#[cfg(target_arch = "x86_64")]
+macro_rules! latency_type {
+    ($t:ident, $registry:ident, $options:ident, $seed:ident, $overhead:ident) => {{
+        if $options.measure_type(stringify!($t)) {
+            let options = &$options;
+            let overhead = $overhead;
+            let max_digits = input::max_digits($t::MAX as u128);
+            let datasets: Vec<(u32, Vec<$t>)> = (1..=max_digits)
+                .map(|digits| {
+                    let strategy = input::RandomGen::DigitLength(digits);
+                    (digits, input::type_from_random::<$t>(strategy, options.samples, $seed))
+                })
+                .collect();
+            println!();
+            println!("## {}", stringify!($t));
+            println!();
+            println!("| Algorithm | Digits | p50 | p90 | p99 |");
+            println!("|:---|---:|---:|---:|---:|");
+            algorithms::$registry!(latency, options, datasets, overhead);
+        }
+    }};
+}
+
+fn usage() -> ! {
+    eprintln!("usage: latency [--type u32|u64] [--algorithm NAME] [--samples N]");
+    process::exit(2);
+}
+
+#[cfg(target_arch = "x86_64")]
+fn run(options: Options) {
+    let seed = input::seed();
+    let overhead = calibrate();
+    println!("# Latency");
+    println!();
+    let seed_var = input::SEED_VAR;
+    println!("Cycles per call, less {} cycles of overhead, with {}={}.", overhead, seed_var, seed);
+    latency_type!(u32, registry_u32, options, seed, overhead);
+    latency_type!(u64, registry_u64, options, seed, overhead);
+}
+
+#[cfg(not(target_arch = "x86_64"))]
+fn run(_: Options) {
+    eprintln!("latency: the timestamp counter is only supported on x86_64");
+    process::exit(1);
+}
+
+fn main() {
+    let mut options = Options {
+        int_type: None,
+        algorithm: None,
+        samples: SAMPLES,
+    };
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        let mut value = || args.next().unwrap_or_else(|| usage());
+        match arg.as_str() {
+            "--type" => options.int_type = Some(value()),
+            "--algorithm" => options.algorithm = Some(value()),
+            "--samples" => options.samples = value().parse().unwrap_or_else(|_| usage()),
+            _ => usage(),
+        }
+    }
+    let valid_type = matches!(options.int_type.as_deref(), None | Some("u32") | Some("u64"));
+    if !valid_type || options.samples == 0 {
+        usage();
+    }
+    run(options);
+}
@@ -0,0 +1,35 @@
+#[macro_use]
+mod input;
+
+use core::time::Duration;
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+// Default random data size.
+const COUNT: usize = 1000;
+
+macro_rules! bench {
+    ($fn:ident, $name:literal, $strategy:expr) => {
+        fn $fn(criterion: &mut Criterion) {
+            let mut group = criterion.benchmark_group($name);
+            group.measurement_time(Duration::from_secs(5));
+            let seed = input::seed();
+
+            let data = input::random_data::<u128>($name, $strategy, COUNT, seed);
+
+            algorithms::registry_u128!(registry_generator, group, data);
+            itoa_generator!(group, "write_u128_itoa", data.iter());
+            fmt_generator!(group, "write_u128_fmt", data.iter());
+        }
+    };
+}
+
+bench!(uniform, "random:uniform", input::RandomGen::Uniform);
+bench!(simple, "random:simple", input::RandomGen::Simple);
+bench!(large, "random:large", input::RandomGen::Large);
+bench!(log_uniform, "random:log_uniform", input::RandomGen::LogUniform);
+criterion_group!(uniform_benches, uniform);
+criterion_group!(simple_benches, simple);
+criterion_group!(large_benches, large);
+criterion_group!(log_uniform_benches, log_uniform);
+criterion_main!(uniform_benches, simple_benches, large_benches, log_uniform_benches);
@@ -13,19 +13,11 @@ macro_rules! bench {
         fn $fn(criterion: &mut Criterion) {
             let mut group = criterion.benchmark_group($name);
             group.measurement_time(Duration::from_secs(5));
-            let seed = fastrand::u64(..);
+            let seed = input::seed();
 
-            let data = input::type_from_random::<u64>($strategy, COUNT, seed), true;
+            let data = input::random_data::<u64>($name, $strategy, COUNT, seed);
 
-            write_u64_generator!(group, jeaiii64_better, data.iter(), true);
-            write_u64_generator!(group, jeaiii64_better_v2, data.iter(), true);
-            write_u64_generator!(group, jeaiii64_better_v3, data.iter(), true);
-            write_u64_generator!(group, jeaiii64_better_v4, data.iter(), true);
-            write_u64_generator!(group, jeaiii64_better_v5, data.iter(), true);
-            write_u64_generator!(group, jeaiii64_better_v6, data.iter(), true);
-            write_u64_generator!(group, alexandrescu64, data.iter(), false);
-            write_u64_generator!(group, naive_temp64, data.iter(), false);
-            write_u64_generator!(group, naive_exact64, data.iter(), false);
+            algorithms::registry_u64!(registry_generator, group, data);
             fmt_generator!(group, concat!("write_u64_fmt"), data.iter());
             itoa_generator!(group, concat!("write_u64_itoa"), data.iter());
         }
@@ -37,9 +29,18 @@ bench!(simple, "random:simple", input::RandomGen::Simple);
 bench!(large, "random:large", input::RandomGen::Large);
 bench!(safe_int, "random:safe_int", input::RandomGen::SafeInt);
 bench!(large_safe_int, "random:large_safe_int", input::RandomGen::LargeSafeInt);
+bench!(log_uniform, "random:log_uniform", input::RandomGen::LogUniform);
 criterion_group!(uniform_benches, uniform);
 criterion_group!(simple_benches, simple);
 criterion_group!(large_benches, large);
 criterion_group!(safe_int_benches, safe_int);
 criterion_group!(large_safe_int_benches, large_safe_int);
-criterion_main!(uniform_benches, simple_benches, large_benches, safe_int_benches, large_safe_int_benches);
+criterion_group!(log_uniform_benches, log_uniform);
+criterion_main!(
+    uniform_benches,
+    simple_benches,
+    large_benches,
+    safe_int_benches,
+    large_safe_int_benches,
+    log_uniform_benches
+);
@@ -13,15 +13,11 @@ macro_rules! bench {
         fn $fn(criterion: &mut Criterion) {
             let mut group = criterion.benchmark_group($name);
             group.measurement_time(Duration::from_secs(5));
-            let seed = fastrand::u64(..);
+            let seed = input::seed();
 
-            let data = input::type_from_random::<u8>($strategy, COUNT, seed);
+            let data = input::random_data::<u8>($name, $strategy, COUNT, seed);
 
-            write_u8_generator!(group, jeaiii8_better, data.iter(), true);
-            write_u8_generator!(group, jeaiii8as32_better, data.iter(), true);
-            write_u8_generator!(group, alexandrescu8, data.iter(), false);
-            write_u8_generator!(group, naive_temp8, data.iter(), false);
-            write_u8_generator!(group, naive_exact8, data.iter(), false);
+            algorithms::registry_u8!(registry_generator, group, data);
             itoa_generator!(group, "write_u8_itoa", data.iter());
             fmt_generator!(group, "write_u8_fmt", data.iter());
         }
@@ -31,7 +27,9 @@ macro_rules! bench {
 bench!(uniform, "random:uniform", input::RandomGen::Uniform);
 bench!(simple, "random:simple", input::RandomGen::Simple);
 bench!(large, "random:large", input::RandomGen::Large);
+bench!(log_uniform, "random:log_uniform", input::RandomGen::LogUniform);
 criterion_group!(uniform_benches, uniform);
 criterion_group!(simple_benches, simple);
 criterion_group!(large_benches, large);
-criterion_main!(uniform_benches, simple_benches, large_benches);
+criterion_group!(log_uniform_benches, log_uniform);
+criterion_main!(uniform_benches, simple_benches, large_benches, log_uniform_benches);
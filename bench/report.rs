@@ -0,0 +1,133 @@
+//! Generate comparison tables from the criterion results.
+//!
+//! Run the benches, and then, for example:
+//!
+//! ```bash
+//! cargo run --release --bin report -- --markdown > results.md
+//! cargo run --release --bin report -- --csv > results.csv
+//! ```
+//!
+//! The options are:
+//! - `--markdown`: Emit a Markdown table per group and type (the default).
+//! - `--csv`: Emit a single CSV table.
+//! - `--baseline NAME`: Use a baseline saved with `--save-baseline`.
+//! - `--dir PATH`: The criterion directory, by default, `target/criterion`.
+
+mod results;
+
+use std::collections::BTreeMap;
+use std::process;
+
+use results::Measurement;
+
+// Get the time of a reference implementation, such as `itoa`, in a table.
+fn reference(rows: &[&Measurement], name: &str) -> Option<f64> {
+    rows.iter().find(|x| x.algorithm() == name).map(|x| x.time)
+}
+
+// Format the time relative to a reference, such as `0.85x`.
+fn relative(time: f64, reference: Option<f64>) -> String {
+    match reference {
+        Some(reference) => format!("{:.2}x", time / reference),
+        None => "-".to_string(),
+    }
+}
+
+// Format the time relative to a reference for a CSV, which is empty if missing.
+fn relative_csv(time: f64, reference: Option<f64>) -> String {
+    reference.map_or(String::new(), |x| format!("{:.4}", time / x))
+}
+
+// The rows for each bench group and type, sorted by the type size.
+type Tables<'a> = BTreeMap<(String, usize, String), Vec<&'a Measurement>>;
+
+// Group the measurements by the bench group and type, so each table only
+// compares algorithms on the same data.
+fn tables(measurements: &[Measurement]) -> Tables<'_> {
+    let mut tables = BTreeMap::new();
+    for measurement in measurements {
+        let order = results::int_type_order(&measurement.int_type);
+        let key = (measurement.group.clone(), order, measurement.int_type.clone());
+        tables.entry(key).or_insert_with(Vec::new).push(measurement);
+    }
+    for rows in tables.values_mut() {
+        rows.sort_by(|x, y| x.time.total_cmp(&y.time));
+    }
+    tables
+}
+
+fn markdown(measurements: &[Measurement]) {
+    for ((group, _, int_type), rows) in tables(measurements) {
+        let itoa = reference(&rows, "itoa");
+        let fmt = reference(&rows, "fmt");
+        if int_type.is_empty() {
+            println!("### {}\n", group);
+        } else {
+            println!("### {} ({})\n", group, int_type);
+        }
+        println!("| Algorithm | Time | vs itoa | vs fmt |");
+        println!("|:--|--:|--:|--:|");
+        for row in rows {
+            println!(
+                "| `{}` | {} | {} | {} |",
+                row.algorithm(),
+                results::format_time(row.time),
+                relative(row.time, itoa),
+                relative(row.time, fmt),
+            );
+        }
+        println!();
+    }
+}
+
+fn csv(measurements: &[Measurement]) {
+    println!("group,type,algorithm,time_ns,error_ns,relative_itoa,relative_fmt");
+    for ((group, _, int_type), rows) in tables(measurements) {
+        let itoa = reference(&rows, "itoa");
+        let fmt = reference(&rows, "fmt");
+        for row in rows {
+            println!(
+                "{},{},{},{:.4},{:.4},{},{}",
+                group,
+                int_type,
+                row.algorithm(),
+                row.time,
+                row.error,
+                relative_csv(row.time, itoa),
+                relative_csv(row.time, fmt),
+            );
+        }
+    }
+}
+
+fn usage() -> ! {
+    eprintln!("usage: report [--markdown | --csv] [--baseline NAME] [--dir PATH]");
+    process::exit(2);
+}
+
+fn main() {
+    let mut is_csv = false;
+    let mut baseline = "new".to_string();
+    let mut dir = results::criterion_dir();
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--markdown" => is_csv = false,
+            "--csv" => is_csv = true,
+            "--baseline" => baseline = args.next().unwrap_or_else(|| usage()),
+            "--dir" => dir = args.next().unwrap_or_else(|| usage()).into(),
+            _ => usage(),
+        }
+    }
+
+    let measurements = results::load(&dir, &baseline);
+    if measurements.is_empty() {
+        eprintln!("no results for baseline \"{}\" in {}", baseline, dir.display());
+        process::exit(1);
+    }
+    if is_csv {
+        csv(&measurements);
+    } else {
+        markdown(&measurements);
+    }
+}
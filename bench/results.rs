@@ -0,0 +1,166 @@
+//! Load the estimates saved by criterion, for the result tools.
+
+// Not every tool uses every helper.
+#![allow(dead_code)]
+
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+
+// The types used in the function names, from smallest to largest.
+const INT_TYPES: [&str; 10] = [
+    "u8", "i8", "u16", "i16", "u32", "i32", "u64", "i64", "u128", "i128",
+];
+
+/// A single measured function within a bench group.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Measurement {
+    /// The bench group, such as `random:uniform`.
+    pub group: String,
+    /// The bench function, such as `write_u64_jeaiii64_better_v4_c`.
+    pub function: String,
+    /// The integer type, such as `u64`, or empty if unknown.
+    pub int_type: String,
+    /// The typical time per iteration, in nanoseconds.
+    pub time: f64,
+    /// The standard error of the time, in nanoseconds.
+    pub error: f64,
+}
+
+impl Measurement {
+    /// Get the name of the algorithm, without the `write_$t_` prefix.
+    pub fn algorithm(&self) -> &str {
+        let prefix = format!("write_{}_", self.int_type);
+        self.function.strip_prefix(prefix.as_str()).unwrap_or(&self.function)
+    }
+
+    /// Get a unique key for the measurement.
+    pub fn key(&self) -> String {
+        format!("{}/{}", self.group, self.function)
+    }
+}
+
+#[derive(Deserialize)]
+struct BenchmarkId {
+    group_id: String,
+    function_id: Option<String>,
+    value_str: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct Estimate {
+    point_estimate: f64,
+    standard_error: f64,
+}
+
+#[derive(Deserialize)]
+struct Estimates {
+    mean: Estimate,
+    slope: Option<Estimate>,
+}
+
+/// Get the criterion output directory, which is `$CRITERION_HOME`, or
+/// `criterion` in the cargo target directory.
+pub fn criterion_dir() -> PathBuf {
+    if let Some(home) = std::env::var_os("CRITERION_HOME") {
+        return PathBuf::from(home);
+    }
+    let mut path = match std::env::var_os("CARGO_TARGET_DIR") {
+        Some(target) => PathBuf::from(target),
+        None => Path::new(env!("CARGO_MANIFEST_DIR")).join("target"),
+    };
+    path.push("criterion");
+    path
+}
+
+/// Get the integer type from a function name, such as `u32` from
+/// `write_u32_jeaiii32_better_c`.
+pub fn int_type(function: &str) -> &str {
+    function.split('_').find(|x| INT_TYPES.contains(x)).unwrap_or("")
+}
+
+/// Get the position of an integer type from smallest to largest, for sorting.
+pub fn int_type_order(int_type: &str) -> usize {
+    INT_TYPES.iter().position(|&x| x == int_type).unwrap_or(INT_TYPES.len())
+}
+
+/// Load every measurement in the criterion directory for a baseline.
+///
+/// The latest run is `new`, the previous run is `base`, and any baselines
+/// saved with `--save-baseline` use their own name.
+pub fn load(dir: &Path, baseline: &str) -> Vec<Measurement> {
+    let mut measurements = Vec::new();
+    visit(dir, baseline, &mut measurements);
+    measurements.sort_by(|x, y| {
+        let x_key = (&x.group, int_type_order(&x.int_type), &x.function);
+        let y_key = (&y.group, int_type_order(&y.int_type), &y.function);
+        x_key.cmp(&y_key)
+    });
+    measurements
+}
+
+// Recursively find the benchmark directories, which contain the baseline.
+fn visit(dir: &Path, baseline: &str, measurements: &mut Vec<Measurement>) {
+    let results = dir.join(baseline);
+    if results.join("estimates.json").is_file() && results.join("benchmark.json").is_file() {
+        if let Some(measurement) = read_measurement(&results) {
+            measurements.push(measurement);
+        }
+        return;
+    }
+
+    let entries = match std::fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(_) => return,
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() && entry.file_name() != "report" {
+            visit(&path, baseline, measurements);
+        }
+    }
+}
+
+// Read a single measurement, skipping any that can't be parsed.
+fn read_measurement(dir: &Path) -> Option<Measurement> {
+    let id = std::fs::read_to_string(dir.join("benchmark.json")).ok()?;
+    let id: BenchmarkId = serde_json::from_str(&id).ok()?;
+    let estimates = std::fs::read_to_string(dir.join("estimates.json")).ok()?;
+    let estimates: Estimates = serde_json::from_str(&estimates).ok()?;
+
+    let mut function = id.function_id.unwrap_or_default();
+    if let Some(value) = id.value_str {
+        function = format!("{}/{}", function, value);
+    }
+    // Criterion reports the slope when it was measured, otherwise, the mean.
+    let estimate = estimates.slope.unwrap_or(estimates.mean);
+    Some(Measurement {
+        group: id.group_id,
+        int_type: int_type(&function).to_string(),
+        function,
+        time: estimate.point_estimate,
+        error: estimate.standard_error,
+    })
+}
+
+/// Format a time in nanoseconds like criterion, such as `950.39 ns`.
+pub fn format_time(ns: f64) -> String {
+    let (value, unit) = if ns < 1e3 {
+        (ns, "ns")
+    } else if ns < 1e6 {
+        (ns / 1e3, "µs")
+    } else if ns < 1e9 {
+        (ns / 1e6, "ms")
+    } else {
+        (ns / 1e9, "s")
+    };
+    // Use 5 significant digits.
+    let precision = if value < 10.0 {
+        4
+    } else if value < 100.0 {
+        3
+    } else {
+        2
+    };
+    format!("{:.*} {}", precision, value, unit)
+}
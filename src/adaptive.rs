@@ -0,0 +1,211 @@
+//! A `u64` writer that learns the distribution of the values it formats.
+//!
+//! The v4 and v5 jeaiii variants, and the SWAR writer, each win on different
+//! distributions. Every `SAMPLE_STRIDE`-th value is sampled into a digit
+//! count histogram, and a reservoir of values. Once a window of samples is
+//! full, if the histogram changed since the last decision, or after
+//! `REEVALUATE_WINDOWS` windows without one, each strategy is timed over the
+//! reservoir, and the fastest is used until the next decision.
+
+use std::time::Instant;
+
+use crate::digit_count::DigitCount;
+use crate::jeaiii::{jeaiii64_better_v4, jeaiii64_better_v5};
+use crate::swar::swar64;
+
+// Sample every N-th value, which keeps the overhead to a counter increment.
+// This must be a power of 2.
+const SAMPLE_STRIDE: u64 = 16;
+
+// The number of samples before a decision.
+const WINDOW_SIZE: u32 = 1024;
+
+// The number of sampled values to time each strategy with, which is the
+// whole window, so a single round is long enough to time reliably.
+const RESERVOIR_SIZE: usize = WINDOW_SIZE as usize;
+
+// The number of timed rounds for each strategy, where the fastest is used.
+// The rounds are interleaved, so any drift affects every strategy equally.
+const ROUNDS: usize = 5;
+
+// Re-evaluate after this many windows, even if the histogram is unchanged,
+// in case an earlier decision was skewed by noise.
+const REEVALUATE_WINDOWS: u32 = 16;
+
+// Re-evaluate if more than this fraction of the samples changed digit count.
+const HISTOGRAM_THRESHOLD: f64 = 0.1;
+
+// One more than the digit count of `u64::MAX`.
+const HISTOGRAM_SIZE: usize = 21;
+
+/// A strategy used by `AdaptiveWriter`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Strategy {
+    /// Split into 32-bit words, with an Alexandrescu fallback for 20 digits,
+    /// using `jeaiii64_better_v4`.
+    Split,
+    /// The 32-bit algorithm, with an Alexandrescu fallback for all larger
+    /// values, using `jeaiii64_better_v5`.
+    Fallback,
+    /// Convert 8 digits at a time, using `swar64`.
+    Swar,
+}
+
+impl Strategy {
+    /// Every strategy, in the order of the counters.
+    pub const ALL: [Strategy; 3] = [Strategy::Split, Strategy::Fallback, Strategy::Swar];
+
+    #[inline(always)]
+    fn write<const CHECKED: bool>(self, value: u64, buffer: &mut [u8]) -> &mut [u8] {
+        match self {
+            Strategy::Split => jeaiii64_better_v4::<CHECKED>(value, buffer),
+            Strategy::Fallback => jeaiii64_better_v5::<CHECKED>(value, buffer),
+            Strategy::Swar => swar64::<CHECKED>(value, buffer),
+        }
+    }
+}
+
+/// The counters for an `AdaptiveWriter`.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct AdaptiveCounters {
+    /// The values written with each strategy, in the order of `Strategy::ALL`.
+    pub writes: [u64; 3],
+    /// The sampled values, by digit count.
+    pub digit_counts: [u64; HISTOGRAM_SIZE],
+    /// The number of times the strategies were timed.
+    pub evaluations: u64,
+    /// The number of times the strategy changed.
+    pub switches: u64,
+}
+
+/// Write `u64` values in decimal, choosing the fastest strategy for the
+/// distribution of the values written so far.
+#[derive(Debug, Clone)]
+pub struct AdaptiveWriter {
+    strategy: Strategy,
+    calls: u64,
+    samples: u32,
+    windows: u32,
+    window: [u32; HISTOGRAM_SIZE],
+    previous: Option<[u32; HISTOGRAM_SIZE]>,
+    reservoir: [u64; RESERVOIR_SIZE],
+    counters: AdaptiveCounters,
+}
+
+impl AdaptiveWriter {
+    /// Create a new writer, starting with the `Split` strategy.
+    #[inline]
+    pub fn new() -> Self {
+        Self::with_strategy(Strategy::Split)
+    }
+
+    /// Create a new writer, starting with a strategy.
+    #[inline]
+    pub fn with_strategy(strategy: Strategy) -> Self {
+        Self {
+            strategy,
+            calls: 0,
+            samples: 0,
+            windows: 0,
+            window: [0; HISTOGRAM_SIZE],
+            previous: None,
+            reservoir: [0; RESERVOIR_SIZE],
+            counters: AdaptiveCounters::default(),
+        }
+    }
+
+    /// Get the current strategy.
+    #[inline]
+    pub fn strategy(&self) -> Strategy {
+        self.strategy
+    }
+
+    /// Get the counters.
+    #[inline]
+    pub fn counters(&self) -> &AdaptiveCounters {
+        &self.counters
+    }
+
+    /// Write the value in decimal, returning the written digits.
+    #[inline(always)]
+    pub fn write_u64<'a, const CHECKED: bool>(
+        &mut self,
+        value: u64,
+        buffer: &'a mut [u8],
+    ) -> &'a mut [u8] {
+        self.calls += 1;
+        if self.calls & (SAMPLE_STRIDE - 1) == 0 {
+            self.sample(value);
+        }
+        self.counters.writes[self.strategy as usize] += 1;
+        self.strategy.write::<CHECKED>(value, buffer)
+    }
+
+    // Add the value to the histogram and reservoir, and evaluate if the
+    // window is full.
+    #[cold]
+    fn sample(&mut self, value: u64) {
+        let count = value.digit_count();
+        self.window[count] += 1;
+        self.counters.digit_counts[count] += 1;
+        self.reservoir[self.samples as usize % RESERVOIR_SIZE] = value;
+        self.samples += 1;
+        if self.samples == WINDOW_SIZE {
+            self.evaluate();
+        }
+    }
+
+    // Time every strategy if the distribution changed, or the last decision
+    // is stale, and use the fastest.
+    fn evaluate(&mut self) {
+        self.windows += 1;
+        let changed = match self.previous {
+            Some(previous) => {
+                let diff: u32 =
+                    previous.iter().zip(self.window.iter()).map(|(&x, &y)| x.abs_diff(y)).sum();
+                // Each changed sample is counted twice, in the old and new count.
+                diff as f64 / (2 * WINDOW_SIZE) as f64 > HISTOGRAM_THRESHOLD
+            },
+            None => true,
+        };
+        if changed || self.windows >= REEVALUATE_WINDOWS {
+            let times = self.time();
+            let fastest = (0..Strategy::ALL.len()).min_by_key(|&x| times[x]).unwrap();
+            let fastest = Strategy::ALL[fastest];
+            if fastest != self.strategy {
+                self.counters.switches += 1;
+                self.strategy = fastest;
+            }
+            self.counters.evaluations += 1;
+            self.windows = 0;
+            self.previous = Some(self.window);
+        }
+        self.window = [0; HISTOGRAM_SIZE];
+        self.samples = 0;
+    }
+
+    // Get the fastest time, in nanoseconds, for each strategy to write the
+    // reservoir, in the order of `Strategy::ALL`.
+    fn time(&self) -> [u128; 3] {
+        let mut buffer = [0u8; 32];
+        let mut best = [u128::MAX; 3];
+        for _ in 0..ROUNDS {
+            for (index, &strategy) in Strategy::ALL.iter().enumerate() {
+                let start = Instant::now();
+                for &value in self.reservoir.iter() {
+                    let value = core::hint::black_box(value);
+                    core::hint::black_box(strategy.write::<false>(value, &mut buffer));
+                }
+                best[index] = best[index].min(start.elapsed().as_nanos());
+            }
+        }
+        best
+    }
+}
+
+impl Default for AdaptiveWriter {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -1,4 +1,6 @@
-use crate::shared::{digit_to_char_const, DigitCount, DIGIT_TO_BASE10_SQUARED};
+use crate::digit_count::DigitCount;
+use crate::formatted_len::FormattedLen;
+use crate::shared::{digit_to_char_const, DIGIT_TO_BASE10_SQUARED};
 
 macro_rules! decode4 {
     (@yes $buffer:ident, $index:ident, $value:ident) => {{
@@ -20,11 +22,27 @@ macro_rules! decode4 {
 }
 
 macro_rules! alexandrescu {
-    ($name:ident, $t:ty, $decode4:ident) => {
+    ($name:ident, $len:ident, $t:ty, $decode4:ident) => {
         // Version that uses an exact digit count to avoid a temp buffer.
         #[inline(always)]
-        pub fn $name<const CHECKED: bool>(mut value: $t, buffer: &mut [u8]) -> &mut [u8] {
-            let count = value.digit_count();
+        pub fn $name<const CHECKED: bool>(value: $t, buffer: &mut [u8]) -> &mut [u8] {
+            $len::<CHECKED>(value, value.digit_count(), buffer)
+        }
+
+        // Version that uses a precomputed digit count, which must be exact.
+        // With `CHECKED`, a wrong count panics, otherwise, it's undefined
+        // behavior, since the digits are written unchecked from the end.
+        #[inline(always)]
+        pub fn $len<const CHECKED: bool>(
+            mut value: $t,
+            count: usize,
+            buffer: &mut [u8],
+        ) -> &mut [u8] {
+            if CHECKED {
+                assert_eq!(count, value.digit_count(), "the digit count must be exact");
+            } else {
+                debug_assert_eq!(count, value.digit_count());
+            }
             let buffer = &mut buffer[..count];
             let mut index = buffer.len();
 
@@ -51,10 +69,85 @@ macro_rules! alexandrescu {
     };
 }
 
-alexandrescu!(alexandrescu8, u8, no);
-alexandrescu!(alexandrescu16, u16, yes);
-alexandrescu!(alexandrescu32, u32, yes);
+alexandrescu!(alexandrescu8, alexandrescu8_len, u8, no);
+alexandrescu!(alexandrescu16, alexandrescu16_len, u16, yes);
+alexandrescu!(alexandrescu32, alexandrescu32_len, u32, yes);
 // TODO: Add in version using `idiv` or the faster u128 division instructions
 // later
-alexandrescu!(alexandrescu64, u64, yes);
-alexandrescu!(alexandrescu128, u128, yes);
+alexandrescu!(alexandrescu64, alexandrescu64_len, u64, yes);
+alexandrescu!(alexandrescu128, alexandrescu128_len, u128, yes);
+
+// Split the value into the sign and absolute value.
+macro_rules! split_sign {
+    (unsigned $value:ident) => {
+        (false, $value)
+    };
+    (signed $value:ident) => {
+        ($value < 0, $value.unsigned_abs())
+    };
+}
+
+macro_rules! alexandrescu_signed {
+    ($name:ident, $t:ty, $len:ident) => {
+        // Write the minus sign, and then the absolute value.
+        #[inline(always)]
+        pub fn $name<const CHECKED: bool>(value: $t, buffer: &mut [u8]) -> &mut [u8] {
+            let count = value.formatted_len();
+            let buffer = &mut buffer[..count];
+            let (negative, abs) = split_sign!(signed value);
+            let mut index = 0;
+            if negative {
+                write_digit_i!(buffer, index, b'-', CHECKED);
+            }
+            _ = $len::<CHECKED>(abs, count - index, &mut buffer[index..]);
+
+            buffer
+        }
+    };
+}
+
+alexandrescu_signed!(alexandrescu_i8, i8, alexandrescu8_len);
+alexandrescu_signed!(alexandrescu_i16, i16, alexandrescu16_len);
+alexandrescu_signed!(alexandrescu_i32, i32, alexandrescu32_len);
+alexandrescu_signed!(alexandrescu_i64, i64, alexandrescu64_len);
+alexandrescu_signed!(alexandrescu_i128, i128, alexandrescu128_len);
+
+macro_rules! alexandrescu_padded {
+    ($name:ident, $t:ty, $len:ident, $kind:ident) => {
+        // Pad the value with leading zeros to at least `width` characters,
+        // after any minus sign, like `{:0width$}`.
+        #[inline(always)]
+        pub fn $name<const CHECKED: bool>(
+            value: $t,
+            width: usize,
+            buffer: &mut [u8],
+        ) -> &mut [u8] {
+            // This is `formatted_len_padded`, but the digit count is reused.
+            let (negative, abs) = split_sign!($kind value);
+            let digits = abs.digit_count();
+            let count = (negative as usize + digits).max(width);
+            let buffer = &mut buffer[..count];
+            let mut index = 0;
+            if negative {
+                write_digit_i!(buffer, index, b'-', CHECKED);
+            }
+            for _ in 0..count - index - digits {
+                write_digit_i!(buffer, index, b'0', CHECKED);
+            }
+            _ = $len::<CHECKED>(abs, digits, &mut buffer[index..]);
+
+            buffer
+        }
+    };
+}
+
+alexandrescu_padded!(alexandrescu_padded8, u8, alexandrescu8_len, unsigned);
+alexandrescu_padded!(alexandrescu_padded16, u16, alexandrescu16_len, unsigned);
+alexandrescu_padded!(alexandrescu_padded32, u32, alexandrescu32_len, unsigned);
+alexandrescu_padded!(alexandrescu_padded64, u64, alexandrescu64_len, unsigned);
+alexandrescu_padded!(alexandrescu_padded128, u128, alexandrescu128_len, unsigned);
+alexandrescu_padded!(alexandrescu_padded_i8, i8, alexandrescu8_len, signed);
+alexandrescu_padded!(alexandrescu_padded_i16, i16, alexandrescu16_len, signed);
+alexandrescu_padded!(alexandrescu_padded_i32, i32, alexandrescu32_len, signed);
+alexandrescu_padded!(alexandrescu_padded_i64, i64, alexandrescu64_len, signed);
+alexandrescu_padded!(alexandrescu_padded_i128, i128, alexandrescu128_len, signed);
@@ -0,0 +1,182 @@
+//! Table-free variants of the Alexandrescu and jeaiii algorithms.
+//!
+//! Rather than indexing `DIGIT_TO_BASE10_SQUARED`, each digit pair is
+//! converted arithmetically and both ASCII bytes are written at once. This
+//! is slower when the table is in the cache, but avoids the cache miss for
+//! sporadic formatting, and has a smaller footprint overall.
+
+use crate::jeaiii_generic::Constants;
+use crate::shared::digit_to_char_const;
+
+/// Convert a value in `0..100` to 2 ASCII digits, without a lookup table.
+///
+/// The digits are packed so the tens digit is the first byte in memory.
+#[inline(always)]
+pub const fn digit_pair(r: u32) -> [u8; 2] {
+    // `(r * 103) >> 10 == r / 10` for all `r < 179`.
+    let tens = (r * 103) >> 10;
+    let ones = r - tens * 10;
+    let packed = (tens | (ones << 8)) as u16 + 0x3030;
+    packed.to_le_bytes()
+}
+
+// Write the 2 digits of `r` forward.
+macro_rules! write_pair_i {
+    ($buffer:ident, $index:ident, $r:expr, $checked:expr) => {{
+        let pair = digit_pair($r as u32);
+        if $checked {
+            $buffer[$index..$index + 2].copy_from_slice(&pair);
+        } else {
+            unsafe {
+                let ptr = $buffer.as_mut_ptr().add($index) as *mut [u8; 2];
+                core::ptr::write_unaligned(ptr, pair);
+            }
+        }
+        $index += 2;
+    }};
+}
+
+// Write the 2 digits of `r` backwards.
+macro_rules! write_pair {
+    ($buffer:ident, $index:ident, $r:expr, $checked:expr) => {{
+        let pair = digit_pair($r as u32);
+        $index -= 2;
+        if $checked {
+            $buffer[$index..$index + 2].copy_from_slice(&pair);
+        } else {
+            unsafe {
+                let ptr = $buffer.as_mut_ptr().add($index) as *mut [u8; 2];
+                core::ptr::write_unaligned(ptr, pair);
+            }
+        }
+    }};
+}
+
+macro_rules! alexandrescu_arith {
+    ($name:ident, $t:ty) => {
+        // Uses `ilog10` for the digit count, which doesn't use a table.
+        #[inline(always)]
+        pub fn $name<const CHECKED: bool>(mut value: $t, buffer: &mut [u8]) -> &mut [u8] {
+            let count = value.checked_ilog10().unwrap_or(0) as usize + 1;
+            let buffer = &mut buffer[..count];
+            let mut index = buffer.len();
+
+            // Decode 2 digits at a time.
+            while value >= 100 {
+                let r = value % 100;
+                value /= 100;
+                write_pair!(buffer, index, r, CHECKED);
+            }
+
+            if value < 10 {
+                let digit = digit_to_char_const(value as u32, 10);
+                write_digit!(buffer, index, digit, CHECKED);
+            } else {
+                write_pair!(buffer, index, value, CHECKED);
+            }
+
+            buffer
+        }
+    };
+}
+
+alexandrescu_arith!(alexandrescu32_arith, u32);
+alexandrescu_arith!(alexandrescu64_arith, u64);
+
+// Write the digits with the constants of the generic jeaiii core, where the
+// leading pair is trimmed to a single digit if `TRIM` and it is less than 10,
+// or if `N` is odd, returning the number of digits written.
+#[inline(always)]
+fn write_digits_arith<const N: usize, const TRIM: bool, const CHECKED: bool>(
+    n: u32,
+    buffer: &mut [u8],
+) -> usize {
+    let buffer = &mut buffer[..N];
+    let mut index = 0;
+    let magic = Constants::<u32, u64, N>::MAGIC;
+    debug_assert!(n as u128 <= Constants::<u32, u64, N>::MAX_INPUT);
+
+    let mask = (1u64 << magic.shift) - 1;
+    let mut prod = (n as u64) * magic.multiplier as u64;
+    let leading = (prod >> magic.shift) as u32;
+    if N % 2 == 1 || (TRIM && leading < 10) {
+        let digit = digit_to_char_const(leading, 10);
+        write_digit_i!(buffer, index, digit, CHECKED);
+    } else {
+        write_pair_i!(buffer, index, leading, CHECKED);
+    }
+    for _ in 0..magic.power / 2 {
+        prod = (prod & mask) * 100;
+        write_pair_i!(buffer, index, prod >> magic.shift, CHECKED);
+    }
+
+    index
+}
+
+// Table-free version of `jeaiii32_better`, with the same branches, using the
+// constants of the generic jeaiii core.
+#[inline(always)]
+pub fn jeaiii32_arith<const CHECKED: bool>(n: u32, buffer: &mut [u8]) -> &mut [u8] {
+    let buffer = &mut buffer[..10];
+    let mut index = 0;
+
+    if n < 100 {
+        if n < 10 {
+            let digit = digit_to_char_const(n, 10);
+            write_digit_i!(buffer, index, digit, CHECKED);
+        } else {
+            write_pair_i!(buffer, index, n, CHECKED);
+        }
+    } else if n < 100_0000 {
+        if n < 1_0000 {
+            index = write_digits_arith::<4, true, CHECKED>(n, buffer);
+        } else {
+            index = write_digits_arith::<6, true, CHECKED>(n, buffer);
+        }
+    } else if n < 1_0000_0000 {
+        index = write_digits_arith::<8, true, CHECKED>(n, buffer);
+    } else if n < 10_0000_0000 {
+        index = write_digits_arith::<9, false, CHECKED>(n, buffer);
+    } else {
+        index = write_digits_arith::<10, false, CHECKED>(n, buffer);
+    }
+
+    &mut buffer[..index]
+}
+
+// Write exactly 8 digits, including leading zeros.
+#[inline(always)]
+fn write_8_arith<const CHECKED: bool>(n: u32, buffer: &mut [u8]) {
+    let count = write_digits_arith::<8, false, CHECKED>(n, buffer);
+    debug_assert_eq!(count, 8);
+}
+
+// Table-free jeaiii algorithm for 64-bit values, splitting values larger
+// than 32 bits into 8-digit chunks.
+#[inline(always)]
+pub fn jeaiii64_arith<const CHECKED: bool>(n: u64, buffer: &mut [u8]) -> &mut [u8] {
+    const FACTOR: u64 = 1_0000_0000;
+    if n <= u32::MAX as u64 {
+        return jeaiii32_arith::<CHECKED>(n as u32, buffer);
+    }
+
+    let buffer = &mut buffer[..20];
+    if n < FACTOR * FACTOR {
+        // 10-16 digits.
+        let hi = (n / FACTOR) as u32;
+        let lo = (n % FACTOR) as u32;
+        let count = jeaiii32_arith::<CHECKED>(hi, buffer).len();
+        write_8_arith::<CHECKED>(lo, &mut buffer[count..]);
+        &mut buffer[..count + 8]
+    } else {
+        // 17-20 digits.
+        let top = (n / (FACTOR * FACTOR)) as u32;
+        let rest = n % (FACTOR * FACTOR);
+        let mid = (rest / FACTOR) as u32;
+        let lo = (rest % FACTOR) as u32;
+        let count = jeaiii32_arith::<CHECKED>(top, buffer).len();
+        write_8_arith::<CHECKED>(mid, &mut buffer[count..]);
+        write_8_arith::<CHECKED>(lo, &mut buffer[count + 8..]);
+        &mut buffer[..count + 16]
+    }
+}
@@ -0,0 +1,187 @@
+//! Branchless calculation of the number of decimal digits in an integer.
+//!
+//! Each implementation indexes a table by the base-2 logarithm of the
+//! value, which determines the number of digits to within 1, and then
+//! rounds up if the value is at least the next power of 10, without any
+//! branches.
+
+#[inline(always)]
+pub(crate) const fn fast_log2(x: u32) -> usize {
+    const BITS: usize = u32::BITS as usize;
+    BITS - 1 - (x | 1).leading_zeros() as usize
+}
+
+#[inline(always)]
+pub(crate) const fn fast_digit_count(x: u32) -> usize {
+    const TABLE: [u64; 32] = [
+        4294967296,
+        8589934582,
+        8589934582,
+        8589934582,
+        12884901788,
+        12884901788,
+        12884901788,
+        17179868184,
+        17179868184,
+        17179868184,
+        21474826480,
+        21474826480,
+        21474826480,
+        21474826480,
+        25769703776,
+        25769703776,
+        25769703776,
+        30063771072,
+        30063771072,
+        30063771072,
+        34349738368,
+        34349738368,
+        34349738368,
+        34349738368,
+        38554705664,
+        38554705664,
+        38554705664,
+        41949672960,
+        41949672960,
+        41949672960,
+        42949672960,
+        42949672960,
+    ];
+    let shift = TABLE[fast_log2(x)];
+    let count = (x as u64 + shift) >> 32;
+    count as usize
+}
+
+#[inline(always)]
+pub(crate) const fn fast_log2_u64(x: u64) -> usize {
+    const BITS: usize = u64::BITS as usize;
+    BITS - 1 - (x | 1).leading_zeros() as usize
+}
+
+#[inline(always)]
+pub(crate) const fn fast_log2_u128(x: u128) -> usize {
+    const BITS: usize = u128::BITS as usize;
+    BITS - 1 - (x | 1).leading_zeros() as usize
+}
+
+// Get the number of digits in `2^log2`.
+const fn pow2_digits(log2: u32) -> u32 {
+    let mut digits = 1;
+    let mut value = 1u128 << log2;
+    while value >= 10 {
+        value /= 10;
+        digits += 1;
+    }
+    digits
+}
+
+// The same as the u32 table, but widened to 128 bits: for values with
+// `log2`, the entry is `digits << 64` if no value with that `log2` has more
+// digits, otherwise, `(digits << 64) + 2^64 - 10^digits`, which carries into
+// the digit count for values of at least `10^digits`.
+const fn digit_count_table_u64() -> [u128; 64] {
+    let mut table = [0u128; 64];
+    let mut log2 = 0;
+    while log2 < 64 {
+        let digits = pow2_digits(log2);
+        let entry = (digits as u128) << 64;
+        table[log2 as usize] = if digits < 20 {
+            entry + (1u128 << 64) - 10u128.pow(digits)
+        } else {
+            entry
+        };
+        log2 += 1;
+    }
+    table
+}
+
+#[inline(always)]
+pub(crate) const fn fast_digit_count_u64(x: u64) -> usize {
+    const TABLE: [u128; 64] = digit_count_table_u64();
+    let shift = TABLE[fast_log2_u64(x)];
+    let count = (x as u128 + shift) >> 64;
+    count as usize
+}
+
+// There's no wider integer to carry into for 128-bit values, so store the
+// minimum digit count and the power of 10 separately, where values of at
+// least that power have 1 more digit. If the power of 10 overflows, all
+// values with that `log2` have the same number of digits, so use the
+// previous power of 10, which is always smaller.
+const fn digit_count_table_u128() -> ([u8; 128], [u128; 128]) {
+    let mut digits = [0u8; 128];
+    let mut powers = [0u128; 128];
+    let mut log2 = 0;
+    while log2 < 128 {
+        let count = pow2_digits(log2);
+        (digits[log2 as usize], powers[log2 as usize]) = match 10u128.checked_pow(count) {
+            Some(power) => (count as u8, power),
+            None => (count as u8 - 1, 10u128.pow(count - 1)),
+        };
+        log2 += 1;
+    }
+    (digits, powers)
+}
+
+#[inline(always)]
+pub(crate) const fn fast_digit_count_u128(x: u128) -> usize {
+    const TABLE: ([u8; 128], [u128; 128]) = digit_count_table_u128();
+    let log2 = fast_log2_u128(x);
+    TABLE.0[log2] as usize + (x >= TABLE.1[log2]) as usize
+}
+
+/// Quickly calculate the number of digits in a type.
+///
+/// For signed integers, this includes the minus sign.
+pub trait DigitCount {
+    /// Get the number of digits in a value.
+    fn digit_count(self) -> usize;
+}
+
+impl DigitCount for u8 {
+    #[inline(always)]
+    fn digit_count(self) -> usize {
+        fast_digit_count(self as _)
+    }
+}
+
+impl DigitCount for u16 {
+    #[inline(always)]
+    fn digit_count(self) -> usize {
+        fast_digit_count(self as _)
+    }
+}
+
+impl DigitCount for u32 {
+    #[inline(always)]
+    fn digit_count(self) -> usize {
+        fast_digit_count(self)
+    }
+}
+
+impl DigitCount for u64 {
+    #[inline(always)]
+    fn digit_count(self) -> usize {
+        fast_digit_count_u64(self)
+    }
+}
+
+impl DigitCount for u128 {
+    #[inline(always)]
+    fn digit_count(self) -> usize {
+        fast_digit_count_u128(self)
+    }
+}
+
+macro_rules! signed_digit_count {
+    ($($t:ty)*) => ($(
+        impl DigitCount for $t {
+            #[inline(always)]
+            fn digit_count(self) -> usize {
+                (self < 0) as usize + self.unsigned_abs().digit_count()
+            }
+        }
+    )*);
+}
+
+signed_digit_count! { i8 i16 i32 i64 i128 }
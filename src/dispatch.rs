@@ -0,0 +1,37 @@
+//! The unified writers, using the fastest algorithm for each type.
+//!
+//! By default, these use the algorithms that are fastest on most CPUs. With
+//! the `autotune` feature, they use the dispatch table in `dispatch_tuned.rs`
+//! instead, which is generated for the local CPU by running
+//! `cargo run --release --bin autotune` in the `bench` directory. The table
+//! is specific to the machine, so it is not checked in, and building with
+//! `autotune` fails until it has been generated.
+
+#[cfg(not(feature = "autotune"))]
+mod table {
+    pub(crate) use crate::jeaiii16as32_better as write_u16;
+    pub(crate) use crate::jeaiii32_better as write_u32;
+    pub(crate) use crate::jeaiii64_better_v4 as write_u64;
+    pub(crate) use crate::jeaiii8as32_better as write_u8;
+}
+
+#[cfg(feature = "autotune")]
+#[path = "dispatch_tuned.rs"]
+mod table;
+
+macro_rules! unified {
+    ($($name:ident $t:ty ;)*) => ($(
+        /// Write the value in decimal, returning the written digits.
+        #[inline(always)]
+        pub fn $name<const CHECKED: bool>(value: $t, buffer: &mut [u8]) -> &mut [u8] {
+            table::$name::<CHECKED>(value, buffer)
+        }
+    )*);
+}
+
+unified! {
+    write_u8 u8 ;
+    write_u16 u16 ;
+    write_u32 u32 ;
+    write_u64 u64 ;
+}
@@ -0,0 +1,99 @@
+//! Integration with `core::fmt`, using the fastest writer for each type.
+//!
+//! The digits are written to a stack buffer, and then padded through
+//! `Formatter::pad_integral`, which is what the standard library uses, so
+//! the width, fill, alignment, `+` and `0` flags are handled identically.
+
+use core::fmt;
+
+use crate::{jeaiii128_generic, write_u16, write_u32, write_u64, write_u8};
+
+// Enough for the 39 digits in `u128::MAX`.
+const BUFFER_SIZE: usize = 40;
+
+/// An integer that can be formatted with the fast decimal writers.
+pub trait DecimalInt: Copy {
+    /// Get if the value is not negative.
+    fn is_nonnegative(self) -> bool;
+
+    /// Write the digits of the absolute value, without a sign.
+    fn write_abs(self, buffer: &mut [u8]) -> &mut [u8];
+}
+
+macro_rules! unsigned_decimal {
+    ($($t:ty, $func:ident ;)*) => ($(
+        impl DecimalInt for $t {
+            #[inline(always)]
+            fn is_nonnegative(self) -> bool {
+                true
+            }
+
+            #[inline(always)]
+            fn write_abs(self, buffer: &mut [u8]) -> &mut [u8] {
+                $func::<true>(self, buffer)
+            }
+        }
+    )*);
+}
+
+unsigned_decimal! {
+    u8, write_u8 ;
+    u16, write_u16 ;
+    u32, write_u32 ;
+    u64, write_u64 ;
+    u128, jeaiii128_generic ;
+}
+
+macro_rules! signed_decimal {
+    ($($t:ty)*) => ($(
+        impl DecimalInt for $t {
+            #[inline(always)]
+            fn is_nonnegative(self) -> bool {
+                self >= 0
+            }
+
+            #[inline(always)]
+            fn write_abs(self, buffer: &mut [u8]) -> &mut [u8] {
+                self.unsigned_abs().write_abs(buffer)
+            }
+        }
+    )*);
+}
+
+signed_decimal! { i8 i16 i32 i64 i128 }
+
+/// Display an integer in decimal using the fast writers.
+///
+/// This honors all the flags supported by the integer `Display` impls,
+/// for example, `format!("{:+08}", Decimal(-5))` is `-0000005`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Decimal<T>(pub T);
+
+impl<T: DecimalInt> fmt::Display for Decimal<T> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut buffer = [b'0'; BUFFER_SIZE];
+        let digits = self.0.write_abs(&mut buffer);
+        // SAFETY: the writers only write ASCII digits.
+        let digits = unsafe { core::str::from_utf8_unchecked(digits) };
+        f.pad_integral(self.0.is_nonnegative(), "", digits)
+    }
+}
+
+/// Write an integer in decimal to a `fmt::Write`, without any padding.
+///
+/// This is faster than `write!(writer, "{}", value)`, since it skips the
+/// `Formatter` entirely.
+#[inline]
+pub fn write_decimal<W: fmt::Write + ?Sized, T: DecimalInt>(
+    writer: &mut W,
+    value: T,
+) -> fmt::Result {
+    let mut buffer = [b'0'; BUFFER_SIZE];
+    if !value.is_nonnegative() {
+        writer.write_char('-')?;
+    }
+    let digits = value.write_abs(&mut buffer);
+    // SAFETY: the writers only write ASCII digits.
+    writer.write_str(unsafe { core::str::from_utf8_unchecked(digits) })
+}
@@ -0,0 +1,65 @@
+//! Calculate the exact number of bytes a writer produces, without writing.
+//!
+//! This allows serializers to measure an entire record, allocate once, and
+//! then write each value. Each length is exactly the length of the slice
+//! returned by the corresponding writer:
+//! - `formatted_len`: every decimal writer, such as `alexandrescu32` and
+//!   `alexandrescu_i32`.
+//! - `formatted_len_radix`: the `naive_radix*` writers.
+//! - `formatted_len_padded`: the `alexandrescu_padded*` writers.
+
+use crate::digit_count::DigitCount;
+
+/// Calculate the number of bytes required to format a value.
+///
+/// For signed integers, this includes the minus sign.
+pub trait FormattedLen: DigitCount + Copy {
+    /// Get the number of bytes to format the value in decimal.
+    #[inline(always)]
+    fn formatted_len(self) -> usize {
+        self.digit_count()
+    }
+
+    /// Get the number of bytes to format the value in a radix in `2..=36`.
+    fn formatted_len_radix(self, radix: u32) -> usize;
+
+    /// Get the number of bytes to format the value in decimal, padded with
+    /// leading zeros to at least `width` bytes, including the sign.
+    #[inline(always)]
+    fn formatted_len_padded(self, width: usize) -> usize {
+        self.formatted_len().max(width)
+    }
+}
+
+macro_rules! unsigned_formatted_len {
+    ($($t:ty)*) => ($(
+        impl FormattedLen for $t {
+            #[inline(always)]
+            fn formatted_len_radix(self, radix: u32) -> usize {
+                debug_assert!((2..=36).contains(&radix));
+                self.checked_ilog(radix as $t).map_or(1, |x| x as usize + 1)
+            }
+        }
+    )*);
+}
+
+unsigned_formatted_len! { u8 u16 u32 u64 u128 }
+
+macro_rules! signed_formatted_len {
+    ($($t:ty)*) => ($(
+        impl FormattedLen for $t {
+            #[inline(always)]
+            fn formatted_len_radix(self, radix: u32) -> usize {
+                (self < 0) as usize + self.unsigned_abs().formatted_len_radix(radix)
+            }
+        }
+    )*);
+}
+
+signed_formatted_len! { i8 i16 i32 i64 i128 }
+
+/// Get the number of bytes to format the value in decimal.
+#[inline(always)]
+pub fn formatted_len<T: FormattedLen>(n: T) -> usize {
+    n.formatted_len()
+}
@@ -0,0 +1,137 @@
+//! Magnitude hints, which reorder the branch ladders of the 64-bit writers.
+//!
+//! The v4 and v5 jeaiii variants always test for 32-bit values first, which
+//! is ideal for small values, but every large value, like a nanosecond
+//! timestamp, pays for the mispredicted small branches. A hint moves the
+//! likely branches first, and the unlikely paths into `#[cold]` functions,
+//! so they are laid out away from the hot path.
+
+use crate::alexandrescu::alexandrescu64;
+use crate::jeaiii::{jeaiii32_better, jeaiii64_better_v4, jeaiii64_better_v5, jeaiii64_split};
+
+/// The expected magnitude of the values written, as a type parameter.
+pub trait MagnitudeHint {
+    /// If the values likely fit in 32 bits, that is, up to 10 digits.
+    const LIKELY_SMALL: bool;
+    /// If the values likely have more than 10 digits.
+    const LIKELY_LARGE: bool;
+}
+
+/// No hint, which uses the default branch order.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct NoHint;
+
+/// Values likely fit in 32 bits, such as counts and lengths.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct LikelySmall;
+
+/// Values likely have 15-20 digits, such as nanosecond timestamps or hashes.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct LikelyLarge;
+
+impl MagnitudeHint for NoHint {
+    const LIKELY_SMALL: bool = false;
+    const LIKELY_LARGE: bool = false;
+}
+
+impl MagnitudeHint for LikelySmall {
+    const LIKELY_SMALL: bool = true;
+    const LIKELY_LARGE: bool = false;
+}
+
+impl MagnitudeHint for LikelyLarge {
+    const LIKELY_SMALL: bool = false;
+    const LIKELY_LARGE: bool = true;
+}
+
+const U32_MAX: u64 = u32::MAX as u64;
+const SPLIT_MAX: u64 = U32_MAX * 10_0000_0000;
+
+// The unlikely values with up to 10 digits.
+#[cold]
+#[inline(never)]
+fn write_small_cold<const CHECKED: bool>(n: u64, buffer: &mut [u8]) -> &mut [u8] {
+    jeaiii32_better::<CHECKED>(n as u32, buffer)
+}
+
+// The unlikely values with 11-19 digits, using the split words.
+#[cold]
+#[inline(never)]
+fn write_split_cold<const CHECKED: bool>(n: u64, buffer: &mut [u8]) -> &mut [u8] {
+    if n <= SPLIT_MAX {
+        jeaiii64_split::<CHECKED>(n, buffer)
+    } else {
+        alexandrescu64::<false>(n, buffer)
+    }
+}
+
+// The unlikely values with 11-20 digits, using the Alexandrescu algorithm.
+#[cold]
+#[inline(never)]
+fn write_fallback_cold(n: u64, buffer: &mut [u8]) -> &mut [u8] {
+    alexandrescu64::<false>(n, buffer)
+}
+
+/// `jeaiii64_better_v4`, with the branches ordered by a magnitude hint.
+#[inline(always)]
+pub fn jeaiii64_better_v4_hint<H: MagnitudeHint, const CHECKED: bool>(
+    n: u64,
+    buffer: &mut [u8],
+) -> &mut [u8] {
+    if H::LIKELY_SMALL {
+        if n <= U32_MAX {
+            jeaiii32_better::<CHECKED>(n as u32, buffer)
+        } else {
+            write_split_cold::<CHECKED>(n, buffer)
+        }
+    } else if H::LIKELY_LARGE {
+        // Test the most common timestamp range first, then 20 digits.
+        if n > U32_MAX && n <= SPLIT_MAX {
+            jeaiii64_split::<CHECKED>(n, buffer)
+        } else if n > SPLIT_MAX {
+            alexandrescu64::<false>(n, buffer)
+        } else {
+            write_small_cold::<CHECKED>(n, buffer)
+        }
+    } else {
+        jeaiii64_better_v4::<CHECKED>(n, buffer)
+    }
+}
+
+/// `jeaiii64_better_v5`, with the branches ordered by a magnitude hint.
+#[inline(always)]
+pub fn jeaiii64_better_v5_hint<H: MagnitudeHint, const CHECKED: bool>(
+    n: u64,
+    buffer: &mut [u8],
+) -> &mut [u8] {
+    if H::LIKELY_SMALL {
+        if n <= U32_MAX {
+            jeaiii32_better::<CHECKED>(n as u32, buffer)
+        } else {
+            write_fallback_cold(n, buffer)
+        }
+    } else if H::LIKELY_LARGE {
+        if n > U32_MAX {
+            alexandrescu64::<false>(n, buffer)
+        } else {
+            write_small_cold::<CHECKED>(n, buffer)
+        }
+    } else {
+        jeaiii64_better_v5::<CHECKED>(n, buffer)
+    }
+}
+
+/// Write a `u64` in decimal with a magnitude hint, returning the digits.
+///
+/// This is `write_u64` for `NoHint`, otherwise, the hinted v4 variant.
+#[inline(always)]
+pub fn write_u64_hint<H: MagnitudeHint, const CHECKED: bool>(
+    n: u64,
+    buffer: &mut [u8],
+) -> &mut [u8] {
+    if H::LIKELY_SMALL || H::LIKELY_LARGE {
+        jeaiii64_better_v4_hint::<H, CHECKED>(n, buffer)
+    } else {
+        crate::dispatch::write_u64::<CHECKED>(n, buffer)
+    }
+}
@@ -0,0 +1,133 @@
+//! Per-branch hit counters for the jeaiii writers.
+//!
+//! With the `instrument` feature, every leaf of the branch ladders in the
+//! jeaiii 32-bit and 64-bit writers increments a counter, so the branch
+//! distribution for a dataset can be measured rather than assumed. Without
+//! the feature, the counters always read zero and the writers are unchanged.
+//!
+//! The writers nest: the 64-bit writers call `jeaiii32_better`, which counts
+//! its own branches, so reset the counters before each measured writer.
+
+use core::sync::atomic::{AtomicU64, Ordering};
+
+// The most leaves in any instrumented branch ladder.
+const MAX_BRANCHES: usize = 10;
+
+/// If the writers are instrumented, that is, the `instrument` feature is enabled.
+pub const INSTRUMENTED: bool = cfg!(feature = "instrument");
+
+/// The hit counters for the branches of a writer.
+#[derive(Debug)]
+pub struct BranchCounters {
+    /// The name of the writer, such as `jeaiii64_better_v4`.
+    pub name: &'static str,
+    /// A label for each branch, such as the digit counts it writes.
+    pub branches: &'static [&'static str],
+    hits: [AtomicU64; MAX_BRANCHES],
+}
+
+impl BranchCounters {
+    const fn new(name: &'static str, branches: &'static [&'static str]) -> Self {
+        assert!(branches.len() <= MAX_BRANCHES);
+        Self {
+            name,
+            branches,
+            hits: [const { AtomicU64::new(0) }; MAX_BRANCHES],
+        }
+    }
+
+    // Count a hit for a branch, by index in `branches`.
+    #[cfg(feature = "instrument")]
+    #[inline(always)]
+    pub(crate) fn hit(&self, branch: usize) {
+        self.hits[branch].fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Get the hits for each branch, in the order of `branches`.
+    pub fn hits(&self) -> Vec<u64> {
+        let hits = &self.hits[..self.branches.len()];
+        hits.iter().map(|x| x.load(Ordering::Relaxed)).collect()
+    }
+
+    /// Get the total hits for every branch.
+    pub fn total(&self) -> u64 {
+        self.hits().iter().sum()
+    }
+
+    /// Reset every counter to zero.
+    pub fn reset(&self) {
+        self.hits.iter().for_each(|x| x.store(0, Ordering::Relaxed));
+    }
+}
+
+// Count a hit for a branch, only with the `instrument` feature.
+macro_rules! count_branch {
+    ($counters:ident, $branch:expr) => {
+        #[cfg(feature = "instrument")]
+        crate::instrument::$counters.hit($branch);
+    };
+}
+
+/// The branches of `jeaiii32_original`.
+pub static JEAIII32_ORIGINAL: BranchCounters = BranchCounters::new(
+    "jeaiii32_original",
+    &["1", "2", "3", "4", "5", "6", "7", "8", "9", "10"],
+);
+
+/// The branches of `jeaiii32_better`.
+pub static JEAIII32_BETTER: BranchCounters = BranchCounters::new(
+    "jeaiii32_better",
+    &["1", "2", "3-4", "5-6", "7-8", "9", "10"],
+);
+
+/// The branches of `jeaiii32_digits`.
+pub static JEAIII32_DIGITS: BranchCounters = BranchCounters::new(
+    "jeaiii32_digits",
+    &["1", "2", "3-4", "5-6", "7-8", "9", "10"],
+);
+
+/// The branches of `jeaiii64_better`.
+pub static JEAIII64_BETTER: BranchCounters =
+    BranchCounters::new("jeaiii64_better", &["1-10", "11-19", "19-20"]);
+
+/// The branches of `jeaiii64_better_v2`.
+pub static JEAIII64_BETTER_V2: BranchCounters =
+    BranchCounters::new("jeaiii64_better_v2", &["1-10", "11-19", "19-20"]);
+
+/// The branches of `jeaiii64_better_v3`.
+pub static JEAIII64_BETTER_V3: BranchCounters =
+    BranchCounters::new("jeaiii64_better_v3", &["1-10", "11-20"]);
+
+/// The branches of `jeaiii64_better_v4`.
+pub static JEAIII64_BETTER_V4: BranchCounters =
+    BranchCounters::new("jeaiii64_better_v4", &["1-10", "11-19", "19-20"]);
+
+/// The branches of `jeaiii64_better_v5`.
+pub static JEAIII64_BETTER_V5: BranchCounters =
+    BranchCounters::new("jeaiii64_better_v5", &["1-10", "11-20"]);
+
+/// The branches of `jeaiii64_better_v6`.
+pub static JEAIII64_BETTER_V6: BranchCounters = BranchCounters::new(
+    "jeaiii64_better_v6",
+    &["1", "2", "3-4", "5-6", "7-8", "9", "10", "10-20"],
+);
+
+/// Every instrumented 32-bit writer.
+pub static BRANCH_COUNTERS_U32: &[&BranchCounters] =
+    &[&JEAIII32_ORIGINAL, &JEAIII32_BETTER, &JEAIII32_DIGITS];
+
+/// Every instrumented 64-bit writer.
+pub static BRANCH_COUNTERS_U64: &[&BranchCounters] = &[
+    &JEAIII64_BETTER,
+    &JEAIII64_BETTER_V2,
+    &JEAIII64_BETTER_V3,
+    &JEAIII64_BETTER_V4,
+    &JEAIII64_BETTER_V5,
+    &JEAIII64_BETTER_V6,
+];
+
+/// Reset the counters of every instrumented writer.
+pub fn reset_branch_counters() {
+    let counters = BRANCH_COUNTERS_U32.iter().chain(BRANCH_COUNTERS_U64.iter());
+    counters.for_each(|x| x.reset());
+}
@@ -1,35 +1,13 @@
 #![allow(dead_code, unused)]
 
 use crate::alexandrescu::alexandrescu64;
-use crate::shared::{copy_to_dst, digit_to_char_const, DigitCount, DIGIT_TO_BASE10_SQUARED};
-
-macro_rules! write_digit_i {
-    ($buffer:ident, $index:ident, $digit:expr, $checked:expr) => {{
-        let digit = $digit;
-        if $checked {
-            $buffer[$index] = digit;
-        } else {
-            unsafe {
-                let ptr = $buffer.get_unchecked_mut($index) as *mut u8;
-                core::ptr::write(ptr, digit);
-            }
-        }
-        $index += 1;
-    }};
-}
-
-macro_rules! write_digits_i {
-    ($buffer:ident, $index:ident, $r:expr, $table:ident, $checked:expr) => {{
-        let r = $r as usize;
-        write_digit_i!($buffer, $index, *i!($table[r]), $checked);
-        write_digit_i!($buffer, $index, *i!($table[r + 1]), $checked);
-    }};
-}
+use crate::digit_count::DigitCount;
+use crate::shared::{copy_to_dst, digit_to_char_const, DIGIT_TO_BASE10_SQUARED};
 
 // Optimized version when printing exactly 10 digits.
 // This contains leading 0s.
-// NOTE: This does **NOT** work for values outside the range, that is, between 99_9999_9998
-// and 99_9999_9999, so it is only safe up to u32_max.
+// NOTE: The constants are exact for every value up to 59_6895_3945, which is
+// `Magic::max_input()`, so this is safe for every u32.
 #[inline(always)]
 pub fn jeaiii32_10<const CHECKED: bool>(n: u32, buffer: &mut [u8]) -> &mut [u8] {
     let buffer = &mut buffer[..10];
@@ -87,9 +65,11 @@ pub fn jeaiii32_original<const CHECKED: bool>(n: u32, buffer: &mut [u8]) -> &mut
         if n < 10 {
             let digit = digit_to_char_const(n, 10);
             write_digit_i!(buffer, index, digit, CHECKED);
+            count_branch!(JEAIII32_ORIGINAL, 0);
             &mut buffer[..1]
         } else {
-            write_digits_i!(buffer, index, n as u32 * 2, DIGIT_TO_BASE10_SQUARED, CHECKED);
+            write_digits_i!(buffer, index, n * 2, DIGIT_TO_BASE10_SQUARED, CHECKED);
+            count_branch!(JEAIII32_ORIGINAL, 1);
             &mut buffer[..2]
         }
     } else if n < 100_0000 {
@@ -107,6 +87,7 @@ pub fn jeaiii32_original<const CHECKED: bool>(n: u32, buffer: &mut [u8]) -> &mut
                     DIGIT_TO_BASE10_SQUARED,
                     CHECKED
                 );
+                count_branch!(JEAIII32_ORIGINAL, 2);
                 &mut buffer[..3]
             } else {
                 write_digits_i!(
@@ -124,6 +105,7 @@ pub fn jeaiii32_original<const CHECKED: bool>(n: u32, buffer: &mut [u8]) -> &mut
                     DIGIT_TO_BASE10_SQUARED,
                     CHECKED
                 );
+                count_branch!(JEAIII32_ORIGINAL, 3);
                 &mut buffer[..4]
             }
         } else {
@@ -150,6 +132,7 @@ pub fn jeaiii32_original<const CHECKED: bool>(n: u32, buffer: &mut [u8]) -> &mut
                     DIGIT_TO_BASE10_SQUARED,
                     CHECKED
                 );
+                count_branch!(JEAIII32_ORIGINAL, 4);
                 &mut buffer[..5]
             } else {
                 // 6 digits.
@@ -176,6 +159,7 @@ pub fn jeaiii32_original<const CHECKED: bool>(n: u32, buffer: &mut [u8]) -> &mut
                     DIGIT_TO_BASE10_SQUARED,
                     CHECKED
                 );
+                count_branch!(JEAIII32_ORIGINAL, 5);
                 &mut buffer[..6]
             }
         }
@@ -194,6 +178,7 @@ pub fn jeaiii32_original<const CHECKED: bool>(n: u32, buffer: &mut [u8]) -> &mut
             write_digits_i!(buffer, index, (y >> 47) as u32 * 2, DIGIT_TO_BASE10_SQUARED, CHECKED);
             y = (y & mask) * 100;
             write_digits_i!(buffer, index, (y >> 47) as u32 * 2, DIGIT_TO_BASE10_SQUARED, CHECKED);
+            count_branch!(JEAIII32_ORIGINAL, 6);
             &mut buffer[..7]
         } else {
             // 8 digits.
@@ -204,6 +189,7 @@ pub fn jeaiii32_original<const CHECKED: bool>(n: u32, buffer: &mut [u8]) -> &mut
             write_digits_i!(buffer, index, (y >> 47) as u32 * 2, DIGIT_TO_BASE10_SQUARED, CHECKED);
             y = (y & mask) * 100;
             write_digits_i!(buffer, index, (y >> 47) as u32 * 2, DIGIT_TO_BASE10_SQUARED, CHECKED);
+            count_branch!(JEAIII32_ORIGINAL, 7);
             &mut buffer[..8]
         }
     } else {
@@ -223,6 +209,7 @@ pub fn jeaiii32_original<const CHECKED: bool>(n: u32, buffer: &mut [u8]) -> &mut
             write_digits_i!(buffer, index, (y >> 57) as u32 * 2, DIGIT_TO_BASE10_SQUARED, CHECKED);
             y = (y & mask) * 100;
             write_digits_i!(buffer, index, (y >> 57) as u32 * 2, DIGIT_TO_BASE10_SQUARED, CHECKED);
+            count_branch!(JEAIII32_ORIGINAL, 8);
             &mut buffer[..9]
         } else {
             // 10 digits.
@@ -235,6 +222,7 @@ pub fn jeaiii32_original<const CHECKED: bool>(n: u32, buffer: &mut [u8]) -> &mut
             write_digits_i!(buffer, index, (y >> 57) as u32 * 2, DIGIT_TO_BASE10_SQUARED, CHECKED);
             y = (y & mask) * 100;
             write_digits_i!(buffer, index, (y >> 57) as u32 * 2, DIGIT_TO_BASE10_SQUARED, CHECKED);
+            count_branch!(JEAIII32_ORIGINAL, 9);
             &mut buffer[..10]
         }
     }
@@ -282,9 +270,8 @@ macro_rules! print_i {
 }
 
 // Better versiom of the jeaiii algorithm
-// NOTE: This one is not correct for 9-10 digits it seems
-// Not a big deal since the performance difference is minimal between this and
-// the original
+// This has 7 leaves rather than the 10 of the original, since the 3-4, 5-6
+// and 7-8 digit branches each write either count with one multiplication.
 #[inline(always)]
 pub fn jeaiii32_better<const CHECKED: bool>(n: u32, buffer: &mut [u8]) -> &mut [u8] {
     let buffer = &mut buffer[..10];
@@ -294,9 +281,11 @@ pub fn jeaiii32_better<const CHECKED: bool>(n: u32, buffer: &mut [u8]) -> &mut [
         if n < 10 {
             let digit = digit_to_char_const(n, 10);
             write_digit_i!(buffer, index, digit, CHECKED);
+            count_branch!(JEAIII32_BETTER, 0);
             &mut buffer[..1]
         } else {
-            write_digits_i!(buffer, index, n as u32 * 2, DIGIT_TO_BASE10_SQUARED, CHECKED);
+            write_digits_i!(buffer, index, n * 2, DIGIT_TO_BASE10_SQUARED, CHECKED);
+            count_branch!(JEAIII32_BETTER, 1);
             &mut buffer[..2]
         }
     } else {
@@ -305,11 +294,13 @@ pub fn jeaiii32_better<const CHECKED: bool>(n: u32, buffer: &mut [u8]) -> &mut [
                 // 3 or 4 digits.
                 // 42949673 = ceil(2^32 / 10^2)
                 print_i!(buffer, index, CHECKED, n, 42949673u64, 0, 1);
+                count_branch!(JEAIII32_BETTER, 2);
                 &mut buffer[..index]
             } else {
                 // 5 or 6 digits.
                 // 429497 = ceil(2^32 / 10^4)
                 print_i!(buffer, index, CHECKED, n, 429497u64, 0, 2);
+                count_branch!(JEAIII32_BETTER, 3);
                 &mut buffer[..index]
             }
         } else {
@@ -317,6 +308,7 @@ pub fn jeaiii32_better<const CHECKED: bool>(n: u32, buffer: &mut [u8]) -> &mut [
                 // 7 or 8 digits.
                 // 281474978 = ceil(2^48 / 10^6) + 1
                 print_i!(buffer, index, CHECKED, n, 281474978u64, 16, 3);
+                count_branch!(JEAIII32_BETTER, 4);
                 &mut buffer[..index]
             } else {
                 if n < 10_0000_0000 {
@@ -330,6 +322,7 @@ pub fn jeaiii32_better<const CHECKED: bool>(n: u32, buffer: &mut [u8]) -> &mut [
                     print_2!(buffer, index, prod, CHECKED);
                     print_2!(buffer, index, prod, CHECKED);
                     print_2!(buffer, index, prod, CHECKED);
+                    count_branch!(JEAIII32_BETTER, 5);
                     &mut buffer[..index]
                 } else {
                     // 10 digits.
@@ -347,6 +340,7 @@ pub fn jeaiii32_better<const CHECKED: bool>(n: u32, buffer: &mut [u8]) -> &mut [
                     print_2!(buffer, index, prod, CHECKED);
                     print_2!(buffer, index, prod, CHECKED);
                     print_2!(buffer, index, prod, CHECKED);
+                    count_branch!(JEAIII32_BETTER, 6);
                     &mut buffer[..index]
                 }
             }
@@ -366,14 +360,28 @@ pub fn jeaiii32_digits<const CHECKED: bool>(n: u32, buffer: &mut [u8]) -> &mut [
 
     match count {
         1 => {
+            count_branch!(JEAIII32_DIGITS, 0);
             let digit = digit_to_char_const(n, 10);
             write_digit_i!(buffer, index, digit, CHECKED);
         },
-        2 => write_digits_i!(buffer, index, n as u32 * 2, DIGIT_TO_BASE10_SQUARED, CHECKED),
-        3 | 4 => print_i!(buffer, index, CHECKED, n, 42949673u64, 0, 1),
-        5 | 6 => print_i!(buffer, index, CHECKED, n, 429497u64, 0, 2),
-        7 | 8 => print_i!(buffer, index, CHECKED, n, 281474978u64, 16, 3),
+        2 => {
+            count_branch!(JEAIII32_DIGITS, 1);
+            write_digits_i!(buffer, index, n * 2, DIGIT_TO_BASE10_SQUARED, CHECKED);
+        },
+        3 | 4 => {
+            count_branch!(JEAIII32_DIGITS, 2);
+            print_i!(buffer, index, CHECKED, n, 42949673u64, 0, 1);
+        },
+        5 | 6 => {
+            count_branch!(JEAIII32_DIGITS, 3);
+            print_i!(buffer, index, CHECKED, n, 429497u64, 0, 2);
+        },
+        7 | 8 => {
+            count_branch!(JEAIII32_DIGITS, 4);
+            print_i!(buffer, index, CHECKED, n, 281474978u64, 16, 3);
+        },
         9 => {
+            count_branch!(JEAIII32_DIGITS, 5);
             // 9 digits.
             // 1441151882 = ceil(2^57 / 10^8) + 1
             let mut prod = (n as u64) * 1441151882u64;
@@ -386,6 +394,7 @@ pub fn jeaiii32_digits<const CHECKED: bool>(n: u32, buffer: &mut [u8]) -> &mut [
             print_2!(buffer, index, prod, CHECKED);
         },
         _ => {
+            count_branch!(JEAIII32_DIGITS, 6);
             // 10 digits.
             // 1441151881 = ceil(2^57 / 10^8)
             let mut prod = (n as u64) * 1441151881u64;
@@ -407,10 +416,9 @@ pub fn jeaiii32_digits<const CHECKED: bool>(n: u32, buffer: &mut [u8]) -> &mut [
     buffer
 }
 
-// Better versiom of the jeaiii algorithm
-// NOTE: This one is not correct for 9-10 digits it seems
-// Not a big deal since the performance difference is minimal between this and
-// the original
+// Better versiom of the jeaiii algorithm for `u8`
+// This has a leaf for each of the 1-3 digit counts, and only multiplies for 3
+// digits, using the 3-4 digit constant of `jeaiii32_better`.
 #[inline(always)]
 pub fn jeaiii8_better<const CHECKED: bool>(n: u8, buffer: &mut [u8]) -> &mut [u8] {
     let buffer = &mut buffer[..10];
@@ -437,10 +445,9 @@ pub fn jeaiii8as32_better<const CHECKED: bool>(n: u8, buffer: &mut [u8]) -> &mut
     jeaiii32_better::<CHECKED>(n as u32, buffer)
 }
 
-// Better versiom of the jeaiii algorithm
-// NOTE: This one is not correct for 9-10 digits it seems
-// Not a big deal since the performance difference is minimal between this and
-// the original
+// Better versiom of the jeaiii algorithm for `u16`
+// This has 4 leaves, for 1 and 2 digits, and the 3-4 and 5-6 digit branches of
+// `jeaiii32_better`, where the latter only writes 5 digits for a `u16`.
 #[inline(always)]
 pub fn jeaiii16_better<const CHECKED: bool>(n: u16, buffer: &mut [u8]) -> &mut [u8] {
     let buffer = &mut buffer[..10];
@@ -485,6 +492,7 @@ pub fn jeaiii64_better<const CHECKED: bool>(n: u64, buffer: &mut [u8]) -> &mut [
     const FACTOR: u64 = 10_0000_0000;
     if n <= U32_MAX {
         // Up to 10 digits
+        count_branch!(JEAIII64_BETTER, 0);
         jeaiii32_better::<CHECKED>(n as u32, buffer)
     } else if n <= U32_MAX * FACTOR {
         // NOTE: Our lo will be 9 digits, so we can write those digits
@@ -492,6 +500,7 @@ pub fn jeaiii64_better<const CHECKED: bool>(n: u64, buffer: &mut [u8]) -> &mut [
         // easy.
 
         // 11-19 digits
+        count_branch!(JEAIII64_BETTER, 1);
         let hi = (n / FACTOR) as u32;
         let lo = (n % FACTOR) as u32;
         // NOTE: We store this value so we can write 10, then just re-assign.
@@ -506,6 +515,7 @@ pub fn jeaiii64_better<const CHECKED: bool>(n: u64, buffer: &mut [u8]) -> &mut [
         buffer
     } else {
         // have 19-20 digits
+        count_branch!(JEAIII64_BETTER, 2);
         let buffer = &mut buffer[..20];
         // hi can be 1-2 digits, the rest are 9 digits
         let mid = n / FACTOR;
@@ -522,7 +532,7 @@ pub fn jeaiii64_better<const CHECKED: bool>(n: u64, buffer: &mut [u8]) -> &mut [
         _ = jeaiii32_10::<CHECKED>(lo, &mut buffer[index + 9..]);
         _ = jeaiii32_10::<CHECKED>(mid, &mut buffer[index..index + 10]);
         if hi < 10 {
-            buffer[0] = digit_to_char_const(hi as u32, 10);
+            buffer[0] = digit_to_char_const(hi, 10);
         } else {
             let r = 2 * hi as usize;
             buffer[0] = *i!(DIGIT_TO_BASE10_SQUARED[r]);
@@ -543,6 +553,7 @@ pub fn jeaiii64_better_v2<const CHECKED: bool>(n: u64, buffer: &mut [u8]) -> &mu
     const FACTOR: u64 = 10_0000_0000;
     if n <= U32_MAX {
         // Up to 10 digits
+        count_branch!(JEAIII64_BETTER_V2, 0);
         jeaiii32_better::<CHECKED>(n as u32, buffer)
     } else {
         // 10-20 digits
@@ -557,14 +568,16 @@ pub fn jeaiii64_better_v2<const CHECKED: bool>(n: u64, buffer: &mut [u8]) -> &mu
             // have 10-19 digits, have `hi` (1-9 digits) and `lo` (10 digits)
             // we can guarantee `hi` is in 9 digits because `u32_max / FACTOR`
             // is at most 9 digits.
+            count_branch!(JEAIII64_BETTER_V2, 1);
             index = jeaiii32_better::<CHECKED>(div as u32, buffer).len() - 1;
         } else {
             // have 19-20 digits, have `hi` (1-2 digits), `mid` (9 digits), and `lo` (9
             // digits)
+            count_branch!(JEAIII64_BETTER_V2, 2);
             let hi = (div / FACTOR) as u32;
-            div = div % FACTOR;
+            div %= FACTOR;
             if hi < 10 {
-                buffer[0] = digit_to_char_const(hi as u32, 10);
+                buffer[0] = digit_to_char_const(hi, 10);
                 index = 0;
             } else {
                 let r = 2 * hi as usize;
@@ -600,9 +613,11 @@ pub fn jeaiii64_better_v3<const CHECKED: bool>(n: u64, buffer: &mut [u8]) -> &mu
     const FACTOR: u64 = 10_0000_0000;
     if n <= U32_MAX {
         // Up to 10 digits
+        count_branch!(JEAIII64_BETTER_V3, 0);
         jeaiii32_better::<CHECKED>(n as u32, buffer)
     } else {
         // 10-20 digits
+        count_branch!(JEAIII64_BETTER_V3, 1);
         // Do an index check here so we can elide all later checks
         let buffer = &mut buffer[..20];
         let div = n / FACTOR;
@@ -624,26 +639,14 @@ pub fn jeaiii64_better_v4<const CHECKED: bool>(n: u64, buffer: &mut [u8]) -> &mu
     const FACTOR: u64 = 10_0000_0000;
     if n <= U32_MAX {
         // Up to 10 digits
+        count_branch!(JEAIII64_BETTER_V4, 0);
         jeaiii32_better::<CHECKED>(n as u32, buffer)
     } else if n <= U32_MAX * FACTOR {
-        // NOTE: Our lo will be 9 digits, so we can write those digits
-        // 2nd and then re-assign the higher bits, which makes this very
-        // easy.
-
         // 11-19 digits
-        let hi = (n / FACTOR) as u32;
-        let lo = (n % FACTOR) as u32;
-        // NOTE: We store this value so we can write 10, then just re-assign.
-        // We just always get the first 20 so the bounds checks can be elided.
-        let buffer = &mut buffer[..20];
-        let count = jeaiii32_better::<CHECKED>(hi, buffer).len();
-        let index = count.saturating_sub(1);
-        let last = buffer[index];
-        let buffer = &mut buffer[..index + 10];
-        _ = jeaiii32_10::<CHECKED>(lo, &mut buffer[index..]);
-        buffer[index] = last;
-        buffer
+        count_branch!(JEAIII64_BETTER_V4, 1);
+        jeaiii64_split::<CHECKED>(n, buffer)
     } else {
+        count_branch!(JEAIII64_BETTER_V4, 2);
         // just do our naive, 2-digit algorithm to avoid any performance issues
         // due to the minimal branching this seems to have way higher performance
         // than our overly-branched implementations
@@ -651,6 +654,28 @@ pub fn jeaiii64_better_v4<const CHECKED: bool>(n: u64, buffer: &mut [u8]) -> &mu
     }
 }
 
+// Write a value with 11-19 digits, that is, `u32::MAX < n <= u32::MAX * 10^9`,
+// as a high word with up to 10 digits, and then the low 9 digits.
+#[inline(always)]
+pub(crate) fn jeaiii64_split<const CHECKED: bool>(n: u64, buffer: &mut [u8]) -> &mut [u8] {
+    const FACTOR: u64 = 10_0000_0000;
+    // NOTE: Our lo will be 9 digits, so we can write those digits
+    // 2nd and then re-assign the higher bits, which makes this very
+    // easy.
+    let hi = (n / FACTOR) as u32;
+    let lo = (n % FACTOR) as u32;
+    // NOTE: We store this value so we can write 10, then just re-assign.
+    // We just always get the first 20 so the bounds checks can be elided.
+    let buffer = &mut buffer[..20];
+    let count = jeaiii32_better::<CHECKED>(hi, buffer).len();
+    let index = count.saturating_sub(1);
+    let last = buffer[index];
+    let buffer = &mut buffer[..index + 10];
+    _ = jeaiii32_10::<CHECKED>(lo, &mut buffer[index..]);
+    buffer[index] = last;
+    buffer
+}
+
 // Very simple approach with just the faster, jeaiii algorithm for 32-bits and
 // the Alexandrescu for the 64-bits one.
 // NOTE: This is slow **EXCEPT** for the safe_int benchmarks which seems
@@ -659,8 +684,10 @@ pub fn jeaiii64_better_v4<const CHECKED: bool>(n: u64, buffer: &mut [u8]) -> &mu
 pub fn jeaiii64_better_v5<const CHECKED: bool>(n: u64, buffer: &mut [u8]) -> &mut [u8] {
     const U32_MAX: u64 = u32::MAX as u64;
     if n <= U32_MAX {
+        count_branch!(JEAIII64_BETTER_V5, 0);
         jeaiii32_better::<CHECKED>(n as u32, buffer)
     } else {
+        count_branch!(JEAIII64_BETTER_V5, 1);
         alexandrescu64::<false>(n, buffer)
     }
 }
@@ -669,7 +696,9 @@ pub fn jeaiii64_better_v5<const CHECKED: bool>(n: u64, buffer: &mut [u8]) -> &mu
 // NOTE: This really isn't fast...
 #[inline(always)]
 pub fn jeaiii64_better_v6<const CHECKED: bool>(n: u64, buffer: &mut [u8]) -> &mut [u8] {
-    const U32_MAX: u64 = u32::MAX as u64;
+    // The largest value the 10-digit constants are exact for, which is less
+    // than `2 * u32::MAX`, so larger values use the fallback.
+    const MAX_10: u64 = 59_6895_3945;
     let mut index = 0;
     let mut buffer = &mut buffer[..20];
 
@@ -677,35 +706,40 @@ pub fn jeaiii64_better_v6<const CHECKED: bool>(n: u64, buffer: &mut [u8]) -> &mu
         if n < 10 {
             let digit = digit_to_char_const(n as u32, 10);
             write_digit_i!(buffer, index, digit, CHECKED);
+            count_branch!(JEAIII64_BETTER_V6, 0);
             &mut buffer[..1]
         } else {
             if n < 100 {
                 write_digits_i!(buffer, index, n as u32 * 2, DIGIT_TO_BASE10_SQUARED, CHECKED);
+                count_branch!(JEAIII64_BETTER_V6, 1);
                 &mut buffer[..2]
             } else {
                 if n < 1_0000 {
                     // 3 or 4 digits.
                     // 42949673 = ceil(2^32 / 10^2)
                     print_i!(buffer, index, CHECKED, n, 42949673u64, 0, 1);
+                    count_branch!(JEAIII64_BETTER_V6, 2);
                     &mut buffer[..index]
                 } else {
                     // 5 or 6 digits.
                     // 429497 = ceil(2^32 / 10^4)
                     print_i!(buffer, index, CHECKED, n, 429497u64, 0, 2);
+                    count_branch!(JEAIII64_BETTER_V6, 3);
                     &mut buffer[..index]
                 }
             }
         }
-    } else if n < U32_MAX * 2 {
+    } else if n <= MAX_10 {
         if n < 1_0000_0000 {
             // 7 or 8 digits.
             // 281474978 = ceil(2^48 / 10^6) + 1
             print_i!(buffer, index, CHECKED, n, 281474978u64, 16, 3);
+            count_branch!(JEAIII64_BETTER_V6, 4);
             &mut buffer[..index]
         } else if n < 10_0000_0000 {
             // 9 digits.
             // 1441151882 = ceil(2^57 / 10^8) + 1
-            let mut prod = (n as u64) * 1441151882u64;
+            let mut prod = n * 1441151882u64;
             prod >>= 25;
             let digit = digit_to_char_const((prod >> 32) as u32, 10);
             write_digit_i!(buffer, index, digit, CHECKED);
@@ -713,11 +747,12 @@ pub fn jeaiii64_better_v6<const CHECKED: bool>(n: u64, buffer: &mut [u8]) -> &mu
             print_2!(buffer, index, prod, CHECKED);
             print_2!(buffer, index, prod, CHECKED);
             print_2!(buffer, index, prod, CHECKED);
+            count_branch!(JEAIII64_BETTER_V6, 5);
             &mut buffer[..index]
         } else {
             // 10 digits.
             // 1441151881 = ceil(2^57 / 10^8)
-            let mut prod = (n as u64) * 1441151881u64;
+            let mut prod = n * 1441151881u64;
             prod >>= 25;
             write_digits_i!(
                 buffer,
@@ -730,9 +765,11 @@ pub fn jeaiii64_better_v6<const CHECKED: bool>(n: u64, buffer: &mut [u8]) -> &mu
             print_2!(buffer, index, prod, CHECKED);
             print_2!(buffer, index, prod, CHECKED);
             print_2!(buffer, index, prod, CHECKED);
+            count_branch!(JEAIII64_BETTER_V6, 6);
             &mut buffer[..index]
         }
     } else {
+        count_branch!(JEAIII64_BETTER_V6, 7);
         alexandrescu64::<false>(n, buffer)
     }
 }
@@ -0,0 +1,275 @@
+//! A generic implementation of the jeaiii algorithm.
+//!
+//! Rather than hard-coding the multiplier and shift for each digit count,
+//! these are derived at compile time from the number of digits, the input
+//! type, and the integer used for the fixed-point product. This allows us
+//! to instantiate the algorithm for any integer type, including writing
+//! larger integers as fixed-width chunks.
+
+use core::marker::PhantomData;
+use core::ops::{BitAnd, Mul, Shr};
+
+use crate::magic::Magic;
+use crate::shared::{digit_to_char_const, DIGIT_TO_BASE10_SQUARED};
+
+/// An integer used for the fixed-point product.
+pub trait JeaiiiWord:
+    Copy + Mul<Output = Self> + Shr<u32, Output = Self> + BitAnd<Output = Self>
+{
+    /// The number of bits in the integer.
+    const BITS: u32;
+
+    /// Truncate a 128-bit value to the word.
+    fn from_u128(value: u128) -> Self;
+
+    /// Convert the word to a 128-bit value.
+    fn as_u128(self) -> u128;
+
+    /// Truncate the word to 32-bits.
+    fn as_u32(self) -> u32;
+}
+
+macro_rules! jeaiii_word {
+    ($($t:ty)*) => ($(
+        impl JeaiiiWord for $t {
+            const BITS: u32 = <$t>::BITS;
+
+            #[inline(always)]
+            fn from_u128(value: u128) -> Self {
+                value as _
+            }
+
+            #[inline(always)]
+            fn as_u128(self) -> u128 {
+                self as _
+            }
+
+            #[inline(always)]
+            fn as_u32(self) -> u32 {
+                self as _
+            }
+        }
+    )*);
+}
+
+jeaiii_word! { u64 u128 }
+
+/// An integer that can be written with the generic jeaiii algorithm.
+pub trait JeaiiiInt: Copy {
+    /// The largest value of the integer.
+    const MAX: u128;
+}
+
+macro_rules! jeaiii_int {
+    ($($t:ty)*) => ($(
+        impl JeaiiiInt for $t {
+            const MAX: u128 = <$t>::MAX as u128;
+        }
+    )*);
+}
+
+jeaiii_int! { u8 u16 u32 u64 }
+
+// The constants for writing `N` digits of `T` using products of `W`.
+pub(crate) struct Constants<T, W, const N: usize>(PhantomData<(T, W)>);
+
+impl<T: JeaiiiInt, W: JeaiiiWord, const N: usize> Constants<T, W, N> {
+    pub(crate) const MAX_INPUT: u128 = {
+        let max = match 10u128.checked_pow(N as u32) {
+            Some(x) => x - 1,
+            None => u128::MAX,
+        };
+        if T::MAX < max {
+            T::MAX
+        } else {
+            max
+        }
+    };
+
+    pub(crate) const MAGIC: Magic = match Magic::find_bounded(N as u32, Self::MAX_INPUT, W::BITS) {
+        Some(magic) => magic,
+        None => panic!("no exact jeaiii constants for this digit count and word size"),
+    };
+}
+
+/// Get the constants used to write `N` digits of `T` with products of `W`,
+/// which are derived at compile time.
+#[inline(always)]
+pub const fn jeaiii_magic<T: JeaiiiInt, W: JeaiiiWord, const N: usize>() -> Magic {
+    Constants::<T, W, N>::MAGIC
+}
+
+// Write the digits, where the leading digit pair is trimmed to a single
+// digit if `TRIM` and the leading pair is less than 10, or if `N` is odd.
+#[inline(always)]
+fn write_digits<T, W, const N: usize, const TRIM: bool, const CHECKED: bool>(
+    n: T,
+    buffer: &mut [u8],
+) -> usize
+where
+    T: JeaiiiInt,
+    W: JeaiiiWord + From<T>,
+{
+    let buffer = &mut buffer[..N];
+    let mut index = 0;
+    let magic = Constants::<T, W, N>::MAGIC;
+    let n = W::from(n);
+    debug_assert!(n.as_u128() <= Constants::<T, W, N>::MAX_INPUT);
+
+    let mask = W::from_u128((1u128 << magic.shift) - 1);
+    let mut prod = n * W::from_u128(magic.multiplier);
+    let leading = (prod >> magic.shift).as_u32();
+    if N % 2 == 1 || (TRIM && leading < 10) {
+        let digit = digit_to_char_const(leading, 10);
+        write_digit_i!(buffer, index, digit, CHECKED);
+    } else {
+        write_digits_i!(buffer, index, leading * 2, DIGIT_TO_BASE10_SQUARED, CHECKED);
+    }
+    for _ in 0..magic.power / 2 {
+        prod = (prod & mask) * W::from_u128(100);
+        let r = (prod >> magic.shift).as_u32() * 2;
+        write_digits_i!(buffer, index, r, DIGIT_TO_BASE10_SQUARED, CHECKED);
+    }
+
+    index
+}
+
+/// Write exactly `N` digits, including leading zeros.
+///
+/// The value must be less than `10^N`. The product is calculated using `W`,
+/// which must be large enough to find exact constants for every `N`-digit
+/// value of `T`, otherwise this fails to compile.
+#[inline(always)]
+pub fn write_n_digits<T, W, const N: usize, const CHECKED: bool>(
+    n: T,
+    buffer: &mut [u8],
+) -> &mut [u8]
+where
+    T: JeaiiiInt,
+    W: JeaiiiWord + From<T>,
+{
+    let count = write_digits::<T, W, N, false, CHECKED>(n, buffer);
+    &mut buffer[..count]
+}
+
+/// Write `N - 1` or `N` digits, without leading zeros.
+///
+/// The value must have `N - 1` or `N` digits, and `N` must be even. This is
+/// the branch of the jeaiii algorithm handling 2 digit counts with a single
+/// multiplication.
+#[inline(always)]
+pub fn write_up_to_n_digits<T, W, const N: usize, const CHECKED: bool>(
+    n: T,
+    buffer: &mut [u8],
+) -> &mut [u8]
+where
+    T: JeaiiiInt,
+    W: JeaiiiWord + From<T>,
+{
+    debug_assert!(N.is_multiple_of(2));
+    let count = write_digits::<T, W, N, true, CHECKED>(n, buffer);
+    &mut buffer[..count]
+}
+
+// The generic version of `jeaiii8_better`, with 2 leaves, since 1 and 2 digits
+// are written by the same branch.
+#[inline(always)]
+pub fn jeaiii8_generic<const CHECKED: bool>(n: u8, buffer: &mut [u8]) -> &mut [u8] {
+    if n < 100 {
+        write_up_to_n_digits::<u8, u64, 2, CHECKED>(n, buffer)
+    } else {
+        write_n_digits::<u8, u64, 3, CHECKED>(n, buffer)
+    }
+}
+
+// The generic version of `jeaiii16_better`, with 3 leaves, since 1 and 2 digits
+// are written by the same branch.
+#[inline(always)]
+pub fn jeaiii16_generic<const CHECKED: bool>(n: u16, buffer: &mut [u8]) -> &mut [u8] {
+    if n < 100 {
+        write_up_to_n_digits::<u16, u64, 2, CHECKED>(n, buffer)
+    } else if n < 1_0000 {
+        write_up_to_n_digits::<u16, u64, 4, CHECKED>(n, buffer)
+    } else {
+        write_n_digits::<u16, u64, 5, CHECKED>(n, buffer)
+    }
+}
+
+// The generic version of `jeaiii32_better`, with 5 leaves rather than 7, since
+// 1-2 and 9-10 digits are also written by a single branch each.
+#[inline(always)]
+pub fn jeaiii32_generic<const CHECKED: bool>(n: u32, buffer: &mut [u8]) -> &mut [u8] {
+    if n < 100 {
+        write_up_to_n_digits::<u32, u64, 2, CHECKED>(n, buffer)
+    } else if n < 100_0000 {
+        if n < 1_0000 {
+            write_up_to_n_digits::<u32, u64, 4, CHECKED>(n, buffer)
+        } else {
+            write_up_to_n_digits::<u32, u64, 6, CHECKED>(n, buffer)
+        }
+    } else if n < 1_0000_0000 {
+        write_up_to_n_digits::<u32, u64, 8, CHECKED>(n, buffer)
+    } else {
+        write_up_to_n_digits::<u32, u64, 10, CHECKED>(n, buffer)
+    }
+}
+
+// Values with up to 10 digits use the 32-bit algorithm, since the 64-bit
+// products are cheaper. There are no exact constants for all 20-digit
+// values in 128 bits, so the top 1-2 digits are split off.
+#[inline(always)]
+pub fn jeaiii64_generic<const CHECKED: bool>(n: u64, buffer: &mut [u8]) -> &mut [u8] {
+    const FACTOR: u64 = 100_0000_0000_0000_0000;
+    if n <= u32::MAX as u64 {
+        jeaiii32_generic::<CHECKED>(n as u32, buffer)
+    } else if n < 1_0000_0000_0000 {
+        if n < 100_0000_0000 {
+            write_up_to_n_digits::<u64, u128, 10, CHECKED>(n, buffer)
+        } else {
+            write_up_to_n_digits::<u64, u128, 12, CHECKED>(n, buffer)
+        }
+    } else if n < 1_0000_0000_0000_0000 {
+        if n < 100_0000_0000_0000 {
+            write_up_to_n_digits::<u64, u128, 14, CHECKED>(n, buffer)
+        } else {
+            write_up_to_n_digits::<u64, u128, 16, CHECKED>(n, buffer)
+        }
+    } else if n < FACTOR {
+        write_up_to_n_digits::<u64, u128, 18, CHECKED>(n, buffer)
+    } else {
+        let buffer = &mut buffer[..20];
+        let hi = (n / FACTOR) as u32;
+        let lo = n % FACTOR;
+        let count = write_up_to_n_digits::<u32, u64, 2, CHECKED>(hi, buffer).len();
+        _ = write_n_digits::<u64, u128, 18, CHECKED>(lo, &mut buffer[count..]);
+        &mut buffer[..count + 18]
+    }
+}
+
+// This is written as 19-digit chunks, which is the most that can be exactly
+// written from a u64 using 128-bit products.
+#[inline(always)]
+pub fn jeaiii128_generic<const CHECKED: bool>(n: u128, buffer: &mut [u8]) -> &mut [u8] {
+    const FACTOR: u128 = 1000_0000_0000_0000_0000;
+    if n <= u64::MAX as u128 {
+        return jeaiii64_generic::<CHECKED>(n as u64, buffer);
+    }
+
+    let buffer = &mut buffer[..39];
+    let hi = n / FACTOR;
+    let lo = (n % FACTOR) as u64;
+    let mut count = if hi <= u64::MAX as u128 {
+        jeaiii64_generic::<CHECKED>(hi as u64, buffer).len()
+    } else {
+        // have 39 digits, the top digit is at most 3
+        let top = (hi / FACTOR) as u32;
+        let mid = (hi % FACTOR) as u64;
+        let count = write_up_to_n_digits::<u32, u64, 2, CHECKED>(top, buffer).len();
+        _ = write_n_digits::<u64, u128, 19, CHECKED>(mid, &mut buffer[count..]);
+        count + 19
+    };
+    _ = write_n_digits::<u64, u128, 19, CHECKED>(lo, &mut buffer[count..]);
+    count += 19;
+
+    &mut buffer[..count]
+}
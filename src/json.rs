@@ -0,0 +1,68 @@
+//! Integration with `serde_json`, using the fast writers for integers.
+//!
+//! Use `JsonFormatter` with `serde_json::Serializer::with_formatter`, or
+//! the `to_json_vec` and `to_json_string` helpers. Everything except integers is
+//! formatted identically to `serde_json::ser::CompactFormatter`.
+
+use std::io;
+
+use serde_json::ser::Formatter;
+
+use crate::display::DecimalInt;
+
+// Enough for the minus sign and the 39 digits in `u128::MAX`.
+const BUFFER_SIZE: usize = 40;
+
+// Write the integer using the fast writers, including the sign.
+#[inline(always)]
+fn write_int<W: ?Sized + io::Write, T: DecimalInt>(writer: &mut W, value: T) -> io::Result<()> {
+    let mut buffer = [b'-'; BUFFER_SIZE];
+    let start = !value.is_nonnegative() as usize;
+    let count = value.write_abs(&mut buffer[start..]).len();
+    writer.write_all(&buffer[..start + count])
+}
+
+/// A compact JSON formatter, which writes integers with the fast writers.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct JsonFormatter;
+
+macro_rules! write_int_methods {
+    ($($meth:ident $t:ty ;)*) => ($(
+        #[inline]
+        fn $meth<W: ?Sized + io::Write>(&mut self, writer: &mut W, value: $t) -> io::Result<()> {
+            write_int(writer, value)
+        }
+    )*);
+}
+
+impl Formatter for JsonFormatter {
+    write_int_methods! {
+        write_u8 u8 ;
+        write_u16 u16 ;
+        write_u32 u32 ;
+        write_u64 u64 ;
+        write_u128 u128 ;
+        write_i8 i8 ;
+        write_i16 i16 ;
+        write_i32 i32 ;
+        write_i64 i64 ;
+        write_i128 i128 ;
+    }
+}
+
+/// Serialize the value as JSON into a byte vector, using `JsonFormatter`.
+#[inline]
+pub fn to_json_vec<T: ?Sized + serde::Serialize>(value: &T) -> serde_json::Result<Vec<u8>> {
+    let mut writer = Vec::with_capacity(128);
+    let mut serializer = serde_json::Serializer::with_formatter(&mut writer, JsonFormatter);
+    value.serialize(&mut serializer)?;
+    Ok(writer)
+}
+
+/// Serialize the value as a JSON string, using `JsonFormatter`.
+#[inline]
+pub fn to_json_string<T: ?Sized + serde::Serialize>(value: &T) -> serde_json::Result<String> {
+    let vec = to_json_vec(value)?;
+    // SAFETY: the serializer only writes valid UTF-8.
+    Ok(unsafe { String::from_utf8_unchecked(vec) })
+}
@@ -2,11 +2,44 @@
 
 #[macro_use]
 mod shared;
+#[macro_use]
+mod instrument;
 
+mod adaptive;
 mod alexandrescu;
+mod arithmetic;
+mod digit_count;
+mod dispatch;
+mod display;
+mod formatted_len;
+mod hint;
 mod jeaiii;
+mod jeaiii_generic;
+#[cfg(feature = "serde")]
+mod json;
+mod magic;
 mod naive;
+mod registry;
+mod swar;
+mod tables;
+mod writer;
 
+pub use adaptive::*;
 pub use alexandrescu::*;
+pub use arithmetic::*;
+pub use digit_count::DigitCount;
+pub use dispatch::*;
+pub use display::*;
+pub use formatted_len::*;
+pub use hint::*;
+pub use instrument::*;
 pub use jeaiii::*;
+pub use jeaiii_generic::*;
+#[cfg(feature = "serde")]
+pub use json::*;
+pub use magic::*;
 pub use naive::*;
+pub use registry::*;
+pub use swar::*;
+pub use tables::*;
+pub use writer::*;
@@ -0,0 +1,237 @@
+//! Generate and verify the fixed-point constants used by the jeaiii
+//! algorithm.
+//!
+//! The jeaiii algorithm multiplies `n` by `multiplier ~= 2^shift / 10^power`,
+//! so the bits above `shift` contain the leading 1-2 digits and the bits
+//! below contain the remaining digits as a binary fraction. Each subsequent
+//! digit pair is extracted by masking off the integer bits and multiplying
+//! by 100. Optionally, the product is shifted right by `pre_shift` bits
+//! first so the fraction is aligned to a convenient width, such as 32 bits.
+//!
+//! Since the multiplier is rounded up, the product slightly overestimates
+//! `n / 10^power`. For the digits to be exact, that error scaled by `n` must
+//! never carry into the integer bits, that is, `n * error < 2^shift` where
+//! `error = multiplier * 10^power - 2^shift`. The pre-shift truncates the
+//! product, which underestimates it by up to `2^pre_shift - 1`, and that
+//! must always be compensated by the rounding error, which gives a lower
+//! bound on the valid inputs.
+
+#[inline(always)]
+const fn pow2(exp: u32) -> Option<u128> {
+    if exp < u128::BITS {
+        Some(1 << exp)
+    } else {
+        None
+    }
+}
+
+#[inline(always)]
+const fn word_max(word_bits: u32) -> u128 {
+    match pow2(word_bits) {
+        Some(x) => x - 1,
+        None => u128::MAX,
+    }
+}
+
+/// Fixed-point constants to extract decimal digits from an integer.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Magic {
+    /// The multiplier, approximately `2^shift / 10^power`.
+    pub multiplier: u128,
+    /// The number of fractional bits in the full product.
+    pub shift: u32,
+    /// The number of bits the product is shifted right before extraction.
+    pub pre_shift: u32,
+    /// The power of 10 divided by, that is, the digits after the leading
+    /// pair. This must be even.
+    pub power: u32,
+    /// The number of bits in the integer used for the product.
+    pub word_bits: u32,
+}
+
+impl Magic {
+    /// Create constants from existing values.
+    #[inline(always)]
+    pub const fn new(
+        multiplier: u128,
+        shift: u32,
+        pre_shift: u32,
+        power: u32,
+        word_bits: u32,
+    ) -> Self {
+        Self {
+            multiplier,
+            shift,
+            pre_shift,
+            power,
+            word_bits,
+        }
+    }
+
+    /// Find the constants with the smallest shift that are exact for every
+    /// value with up to `digits` digits, with a product of `word_bits` bits.
+    #[inline]
+    pub const fn find(digits: u32, word_bits: u32) -> Option<Self> {
+        match 10u128.checked_pow(digits) {
+            Some(x) => Self::find_bounded(digits, x - 1, word_bits),
+            None => None,
+        }
+    }
+
+    /// Find the constants with the smallest shift that are exact for every
+    /// value in `0..=max_input`, where `digits` is the maximum number of
+    /// digits in `max_input`, with a product of `word_bits` bits.
+    pub const fn find_bounded(digits: u32, max_input: u128, word_bits: u32) -> Option<Self> {
+        let power = if digits < 2 {
+            0
+        } else {
+            2 * ((digits - 1) / 2)
+        };
+        let scale = match 10u128.checked_pow(power) {
+            Some(x) => x,
+            None => return None,
+        };
+        let mut shift = 0;
+        while shift < u128::BITS {
+            let multiplier = match pow2(shift) {
+                Some(x) => x.div_ceil(scale),
+                None => return None,
+            };
+            let magic = Self::new(multiplier, shift, 0, power, word_bits);
+            if let Some(max) = magic.max_input() {
+                if max >= max_input {
+                    return Some(magic);
+                }
+            }
+            shift += 1;
+        }
+        None
+    }
+
+    /// Get the rounding error of the multiplier, `multiplier * 10^power -
+    /// 2^shift`, or `None` if the multiplier underestimates `2^shift /
+    /// 10^power`.
+    #[inline]
+    pub const fn error(&self) -> Option<u128> {
+        let scaled = match 10u128.checked_pow(self.power) {
+            Some(x) => self.multiplier.checked_mul(x),
+            None => None,
+        };
+        match (scaled, pow2(self.shift)) {
+            (Some(x), Some(y)) if x >= y => Some(x - y),
+            _ => None,
+        }
+    }
+
+    /// Get the smallest input of the range of exact values, or `None` if
+    /// there are no exact values.
+    ///
+    /// This is only conservative, and not exact, when `pre_shift != 0`.
+    pub const fn min_input(&self) -> Option<u128> {
+        let error = match self.error() {
+            Some(x) => x,
+            None => return None,
+        };
+        if self.pre_shift == 0 || self.power == 0 {
+            return Some(0);
+        } else if error == 0 {
+            return None;
+        }
+        // The truncation from the pre-shift must be compensated by the
+        // rounding error: `n * error >= (2^pre_shift - 1) * 10^power`.
+        let truncated = match (pow2(self.pre_shift), 10u128.checked_pow(self.power)) {
+            (Some(x), Some(y)) => (x - 1).checked_mul(y),
+            _ => None,
+        };
+        match truncated {
+            Some(x) => Some(x.div_ceil(error)),
+            None => None,
+        }
+    }
+
+    /// Get the largest input of the range of exact values, or `None` if
+    /// there are no exact values.
+    ///
+    /// This is only conservative, and not exact, when `pre_shift != 0`.
+    pub const fn max_input(&self) -> Option<u128> {
+        let error = match self.error() {
+            Some(x) => x,
+            None => return None,
+        };
+        if self.pre_shift > self.shift || self.multiplier == 0 {
+            return None;
+        }
+        // Multiplying the fraction by 100 cannot overflow.
+        let word_max = word_max(self.word_bits);
+        let fraction_max = match pow2(self.shift - self.pre_shift) {
+            Some(x) => x - 1,
+            None => return None,
+        };
+        match fraction_max.checked_mul(100) {
+            Some(x) if x <= word_max => (),
+            _ => return None,
+        }
+
+        // The leading digits must fit in a single digit pair.
+        let mut max = match 10u128.checked_pow(self.power + 2) {
+            Some(x) => x - 1,
+            None => u128::MAX,
+        };
+        // The initial product cannot overflow.
+        let limit = word_max / self.multiplier;
+        if limit < max {
+            max = limit;
+        }
+        // The rounding error cannot carry into the integer bits.
+        let limit = match pow2(self.shift) {
+            Some(x) => (x - 1).checked_div(error),
+            None => return None,
+        };
+        if let Some(limit) = limit {
+            if limit < max {
+                max = limit;
+            }
+        }
+
+        Some(max)
+    }
+
+    /// Determine if the extraction is exact for every value in `lo..=hi`.
+    #[inline]
+    pub const fn is_exact_over(&self, lo: u128, hi: u128) -> bool {
+        match (self.min_input(), self.max_input()) {
+            (Some(min), Some(max)) => min <= lo && hi <= max,
+            _ => false,
+        }
+    }
+
+    /// Extract the digits of `n` exactly as the jeaiii algorithm does, and
+    /// check if each digit pair is correct.
+    pub fn check(&self, n: u128) -> bool {
+        let word_max = word_max(self.word_bits);
+        let fraction_bits = self.shift - self.pre_shift;
+        let mask = match pow2(fraction_bits) {
+            Some(x) => x - 1,
+            None => return false,
+        };
+        let mut scale = 10u128.pow(self.power);
+        let mut prod = match n.checked_mul(self.multiplier) {
+            Some(x) if x <= word_max => x >> self.pre_shift,
+            _ => return false,
+        };
+        if prod >> fraction_bits != n / scale || n / scale >= 100 {
+            return false;
+        }
+        while scale > 1 {
+            scale /= 100;
+            prod = match (prod & mask).checked_mul(100) {
+                Some(x) if x <= word_max => x,
+                _ => return false,
+            };
+            if prod >> fraction_bits != (n / scale) % 100 {
+                return false;
+            }
+        }
+        true
+    }
+}
@@ -1,4 +1,6 @@
-use crate::shared::{copy_to_dst, digit_to_char_const, DigitCount};
+use crate::digit_count::DigitCount;
+use crate::formatted_len::FormattedLen;
+use crate::shared::{copy_to_dst, digit_to_char_const};
 
 macro_rules! naive {
     ($name:ident, $t:ty) => {
@@ -49,11 +51,27 @@ temp!(naive_temp64, u64, naive64, 20);
 // TODO: Make u128 faster
 
 macro_rules! exact {
-    ($name:ident, $t:ty, $meth:ident) => {
+    ($name:ident, $len:ident, $t:ty, $meth:ident) => {
         // Version that uses an exact digit count to avoid a temp buffer.
         #[inline(always)]
         pub fn $name<const CHECKED: bool>(value: $t, buffer: &mut [u8]) -> &mut [u8] {
-            let count = value.digit_count();
+            $len::<CHECKED>(value, value.digit_count(), buffer)
+        }
+
+        // Version that uses a precomputed digit count, which must be exact.
+        // With `CHECKED`, a wrong count panics, otherwise, it's undefined
+        // behavior, since the digits are written unchecked from the end.
+        #[inline(always)]
+        pub fn $len<const CHECKED: bool>(
+            value: $t,
+            count: usize,
+            buffer: &mut [u8],
+        ) -> &mut [u8] {
+            if CHECKED {
+                assert_eq!(count, value.digit_count(), "the digit count must be exact");
+            } else {
+                debug_assert_eq!(count, value.digit_count());
+            }
             let buffer = &mut buffer[..count];
             _ = unsafe { $meth::<CHECKED>(value, buffer) };
 
@@ -62,8 +80,70 @@ macro_rules! exact {
     };
 }
 
-exact!(naive_exact8, u8, naive8);
-exact!(naive_exact16, u16, naive16);
-exact!(naive_exact32, u32, naive32);
-exact!(naive_exact64, u64, naive64);
-exact!(naive_exact128, u128, naive128);
+exact!(naive_exact8, naive_exact8_len, u8, naive8);
+exact!(naive_exact16, naive_exact16_len, u16, naive16);
+exact!(naive_exact32, naive_exact32_len, u32, naive32);
+exact!(naive_exact64, naive_exact64_len, u64, naive64);
+exact!(naive_exact128, naive_exact128_len, u128, naive128);
+
+macro_rules! radix {
+    ($name:ident, $t:ty) => {
+        // Version for any radix in `2..=36`, using an exact digit count.
+        #[inline(always)]
+        pub fn $name<const CHECKED: bool>(
+            mut value: $t,
+            radix: u32,
+            buffer: &mut [u8],
+        ) -> &mut [u8] {
+            let count = value.formatted_len_radix(radix);
+            let buffer = &mut buffer[..count];
+            let radix = radix as $t;
+            let mut index = buffer.len();
+            while value >= radix {
+                let r = value % radix;
+                value /= radix;
+                let digit = digit_to_char_const(r as u32, radix as u32);
+                write_digit!(buffer, index, digit, CHECKED);
+            }
+
+            let digit = digit_to_char_const(value as u32, radix as u32);
+            write_digit!(buffer, index, digit, CHECKED);
+
+            buffer
+        }
+    };
+}
+
+radix!(naive_radix8, u8);
+radix!(naive_radix16, u16);
+radix!(naive_radix32, u32);
+radix!(naive_radix64, u64);
+radix!(naive_radix128, u128);
+
+macro_rules! radix_signed {
+    ($name:ident, $t:ty, $unsigned:ident) => {
+        // Write the minus sign, and then the absolute value in the radix.
+        #[inline(always)]
+        pub fn $name<const CHECKED: bool>(
+            value: $t,
+            radix: u32,
+            buffer: &mut [u8],
+        ) -> &mut [u8] {
+            let count = value.formatted_len_radix(radix);
+            let buffer = &mut buffer[..count];
+            let mut index = 0;
+            if value < 0 {
+                write_digit_i!(buffer, index, b'-', CHECKED);
+            }
+            _ = $unsigned::<CHECKED>(value.unsigned_abs(), radix, &mut buffer[index..]);
+
+            buffer
+        }
+    };
+}
+
+radix_signed!(naive_radix_i8, i8, naive_radix8);
+radix_signed!(naive_radix_i16, i16, naive_radix16);
+radix_signed!(naive_radix_i32, i32, naive_radix32);
+radix_signed!(naive_radix_i64, i64, naive_radix64);
+radix_signed!(naive_radix_i128, i128, naive_radix128);
@@ -0,0 +1,215 @@
+//! A registry of every algorithm, with the metadata to test and bench it.
+//!
+//! Each type has a `registry_*!` macro listing its algorithms, which is the
+//! single source of truth: it builds the `ALGORITHMS_*` tables used by the
+//! tests, and the benches expand it directly so every call is inlined. The
+//! macro calls `$callback! { $t, $args... ; $entries... }`, where each entry
+//! is:
+//!
+//! `name, checked, unchecked, bench_checked, width, min, max ;`
+//!
+//! - `checked`, `unchecked`: If the `CHECKED` mode is supported (`yes`/`no`).
+//! - `bench_checked`: The `CHECKED` mode used in the benches.
+//! - `width`: The output width, as a `Width`.
+//! - `min`, `max`: The valid input range.
+
+/// The signature of every writer, for a single `CHECKED` mode.
+pub type WriteFn<T> = fn(T, &mut [u8]) -> &mut [u8];
+
+/// The number of digits written by an algorithm.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Width {
+    /// Only the significant digits are written.
+    Variable,
+    /// Exactly this many digits are written, with leading zeros.
+    Fixed(usize),
+}
+
+/// A registered algorithm for an integer type.
+#[derive(Debug, Copy, Clone)]
+pub struct Algorithm<T: 'static> {
+    /// The name of the function, such as `jeaiii32_better`.
+    pub name: &'static str,
+    /// The name of the integer type, such as `u32`.
+    pub int_type: &'static str,
+    /// The writer with bounds checks, if supported.
+    pub checked: Option<WriteFn<T>>,
+    /// The writer without bounds checks, if supported.
+    pub unchecked: Option<WriteFn<T>>,
+    /// The `CHECKED` mode used in the benches, which is the fastest.
+    pub bench_checked: bool,
+    /// The number of digits written.
+    pub width: Width,
+    /// The smallest valid input.
+    pub min: T,
+    /// The largest valid input.
+    pub max: T,
+}
+
+impl<T> Algorithm<T> {
+    /// Get the writer for a `CHECKED` mode, if supported.
+    #[inline]
+    pub fn writer(&self, checked: bool) -> Option<WriteFn<T>> {
+        if checked {
+            self.checked
+        } else {
+            self.unchecked
+        }
+    }
+
+    /// Get the writer for every supported mode, as `(checked, writer)`.
+    #[inline]
+    pub fn writers(&self) -> impl Iterator<Item = (bool, WriteFn<T>)> {
+        let checked = self.checked.map(|x| (true, x));
+        let unchecked = self.unchecked.map(|x| (false, x));
+        checked.into_iter().chain(unchecked)
+    }
+}
+
+/// Expand the registered `u8` algorithms into a callback macro.
+#[macro_export]
+macro_rules! registry_u8 {
+    ($callback:ident $(, $arg:ident)*) => {
+        $callback! {
+            u8 $(, $arg)* ;
+            jeaiii8_better, yes, yes, true, $crate::Width::Variable, 0, u8::MAX ;
+            jeaiii8as32_better, yes, yes, true, $crate::Width::Variable, 0, u8::MAX ;
+            jeaiii8_generic, yes, yes, true, $crate::Width::Variable, 0, u8::MAX ;
+            alexandrescu8, yes, yes, false, $crate::Width::Variable, 0, u8::MAX ;
+            naive_temp8, yes, yes, false, $crate::Width::Variable, 0, u8::MAX ;
+            naive_exact8, yes, yes, false, $crate::Width::Variable, 0, u8::MAX ;
+        }
+    };
+}
+
+/// Expand the registered `u16` algorithms into a callback macro.
+#[macro_export]
+macro_rules! registry_u16 {
+    ($callback:ident $(, $arg:ident)*) => {
+        $callback! {
+            u16 $(, $arg)* ;
+            jeaiii16_better, yes, yes, true, $crate::Width::Variable, 0, u16::MAX ;
+            jeaiii16as32_better, yes, yes, true, $crate::Width::Variable, 0, u16::MAX ;
+            jeaiii16_generic, yes, yes, true, $crate::Width::Variable, 0, u16::MAX ;
+            alexandrescu16, yes, yes, false, $crate::Width::Variable, 0, u16::MAX ;
+            naive_temp16, yes, yes, false, $crate::Width::Variable, 0, u16::MAX ;
+            naive_exact16, yes, yes, false, $crate::Width::Variable, 0, u16::MAX ;
+        }
+    };
+}
+
+/// Expand the registered `u32` algorithms into a callback macro.
+#[macro_export]
+macro_rules! registry_u32 {
+    ($callback:ident $(, $arg:ident)*) => {
+        $callback! {
+            u32 $(, $arg)* ;
+            jeaiii32_original, yes, yes, true, $crate::Width::Variable, 0, u32::MAX ;
+            jeaiii32_better, yes, yes, true, $crate::Width::Variable, 0, u32::MAX ;
+            jeaiii32_digits, yes, yes, true, $crate::Width::Variable, 0, u32::MAX ;
+            jeaiii32_generic, yes, yes, true, $crate::Width::Variable, 0, u32::MAX ;
+            jeaiii32_10, yes, yes, true, $crate::Width::Fixed(10), 0, u32::MAX ;
+            jeaiii32_10_start, yes, yes, true, $crate::Width::Variable, 0, u32::MAX ;
+            jeaiii32_table4, yes, yes, true, $crate::Width::Variable, 0, u32::MAX ;
+            jeaiii32_arith, yes, yes, true, $crate::Width::Variable, 0, u32::MAX ;
+            swar32, yes, yes, true, $crate::Width::Variable, 0, u32::MAX ;
+            alexandrescu32, yes, yes, false, $crate::Width::Variable, 0, u32::MAX ;
+            alexandrescu32_table3, yes, yes, false, $crate::Width::Variable, 0, u32::MAX ;
+            alexandrescu32_table4, yes, yes, false, $crate::Width::Variable, 0, u32::MAX ;
+            alexandrescu32_arith, yes, yes, false, $crate::Width::Variable, 0, u32::MAX ;
+            naive_temp32, yes, yes, false, $crate::Width::Variable, 0, u32::MAX ;
+            naive_exact32, yes, yes, false, $crate::Width::Variable, 0, u32::MAX ;
+        }
+    };
+}
+
+/// Expand the registered `u64` algorithms into a callback macro.
+#[macro_export]
+macro_rules! registry_u64 {
+    ($callback:ident $(, $arg:ident)*) => {
+        $callback! {
+            u64 $(, $arg)* ;
+            jeaiii64_better, yes, yes, true, $crate::Width::Variable, 0, u64::MAX ;
+            jeaiii64_better_v2, yes, yes, true, $crate::Width::Variable, 0, u64::MAX ;
+            jeaiii64_better_v3, yes, yes, true, $crate::Width::Variable, 0, u64::MAX ;
+            jeaiii64_better_v4, yes, yes, true, $crate::Width::Variable, 0, u64::MAX ;
+            jeaiii64_better_v5, yes, yes, true, $crate::Width::Variable, 0, u64::MAX ;
+            jeaiii64_better_v6, yes, yes, true, $crate::Width::Variable, 0, u64::MAX ;
+            jeaiii64_generic, yes, yes, true, $crate::Width::Variable, 0, u64::MAX ;
+            jeaiii64_table4, yes, yes, true, $crate::Width::Variable, 0, u64::MAX ;
+            jeaiii64_arith, yes, yes, true, $crate::Width::Variable, 0, u64::MAX ;
+            swar64, yes, yes, true, $crate::Width::Variable, 0, u64::MAX ;
+            alexandrescu64, yes, yes, false, $crate::Width::Variable, 0, u64::MAX ;
+            alexandrescu64_table3, yes, yes, false, $crate::Width::Variable, 0, u64::MAX ;
+            alexandrescu64_table4, yes, yes, false, $crate::Width::Variable, 0, u64::MAX ;
+            alexandrescu64_arith, yes, yes, false, $crate::Width::Variable, 0, u64::MAX ;
+            naive_temp64, yes, yes, false, $crate::Width::Variable, 0, u64::MAX ;
+            naive_exact64, yes, yes, false, $crate::Width::Variable, 0, u64::MAX ;
+        }
+    };
+}
+
+/// Expand the registered `u128` algorithms into a callback macro.
+#[macro_export]
+macro_rules! registry_u128 {
+    ($callback:ident $(, $arg:ident)*) => {
+        $callback! {
+            u128 $(, $arg)* ;
+            jeaiii128_generic, yes, yes, true, $crate::Width::Variable, 0, u128::MAX ;
+            alexandrescu128, yes, yes, false, $crate::Width::Variable, 0, u128::MAX ;
+            naive_exact128, yes, yes, false, $crate::Width::Variable, 0, u128::MAX ;
+        }
+    };
+}
+
+macro_rules! write_fn {
+    (yes, $name:ident, $checked:literal) => {
+        Some(crate::$name::<$checked> as WriteFn<_>)
+    };
+    (no, $name:ident, $checked:literal) => {
+        None
+    };
+}
+
+macro_rules! algorithm_table {
+    (
+        $t:ident ;
+        $(
+            $name:ident,
+            $checked:ident,
+            $unchecked:ident,
+            $bench:literal,
+            $width:expr,
+            $min:expr,
+            $max:expr ;
+        )*
+    ) => {
+        &[$(
+            Algorithm {
+                name: stringify!($name),
+                int_type: stringify!($t),
+                checked: write_fn!($checked, $name, true),
+                unchecked: write_fn!($unchecked, $name, false),
+                bench_checked: $bench,
+                width: $width,
+                min: $min,
+                max: $max,
+            },
+        )*]
+    };
+}
+
+/// Every registered `u8` algorithm.
+pub static ALGORITHMS_U8: &[Algorithm<u8>] = crate::registry_u8!(algorithm_table);
+
+/// Every registered `u16` algorithm.
+pub static ALGORITHMS_U16: &[Algorithm<u16>] = crate::registry_u16!(algorithm_table);
+
+/// Every registered `u32` algorithm.
+pub static ALGORITHMS_U32: &[Algorithm<u32>] = crate::registry_u32!(algorithm_table);
+
+/// Every registered `u64` algorithm.
+pub static ALGORITHMS_U64: &[Algorithm<u64>] = crate::registry_u64!(algorithm_table);
+
+/// Every registered `u128` algorithm.
+pub static ALGORITHMS_U128: &[Algorithm<u128>] = crate::registry_u128!(algorithm_table);
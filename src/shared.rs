@@ -16,204 +16,28 @@ pub const DIGIT_TO_BASE10_SQUARED: [u8; 200] = [
     b'9', b'6', b'9', b'7', b'9', b'8', b'9', b'9',
 ];
 
-#[inline(always)]
-pub const fn fast_log2(x: u32) -> usize {
-    const BITS: usize = u32::BITS as usize;
-    BITS as usize - 1 - (x | 1).leading_zeros() as usize
-}
-
-#[inline(always)]
-pub const fn fast_log10(x: u32) -> usize {
-    let log2 = fast_log2(x);
-    (log2 * 1233) >> 12
-}
-
-#[inline(always)]
-pub const fn fast_digit_count(x: u32) -> usize {
-    const TABLE: [u64; 32] = [
-        4294967296,
-        8589934582,
-        8589934582,
-        8589934582,
-        12884901788,
-        12884901788,
-        12884901788,
-        17179868184,
-        17179868184,
-        17179868184,
-        21474826480,
-        21474826480,
-        21474826480,
-        21474826480,
-        25769703776,
-        25769703776,
-        25769703776,
-        30063771072,
-        30063771072,
-        30063771072,
-        34349738368,
-        34349738368,
-        34349738368,
-        34349738368,
-        38554705664,
-        38554705664,
-        38554705664,
-        41949672960,
-        41949672960,
-        41949672960,
-        42949672960,
-        42949672960,
-    ];
-    let shift = TABLE[fast_log2(x)];
-    let count = (x as u64 + shift) >> 32;
-    count as usize
-}
-
-/// Quickly calculate the number of digits in a type.
-pub trait DigitCount {
-    /// Get the number of digits in a value.
-    fn digit_count(self) -> usize;
-}
-
-impl DigitCount for u8 {
-    #[inline(always)]
-    fn digit_count(self) -> usize {
-        fast_digit_count(self as _)
-    }
-}
-
-impl DigitCount for u16 {
-    #[inline(always)]
-    fn digit_count(self) -> usize {
-        fast_digit_count(self as _)
-    }
-}
-
-impl DigitCount for u32 {
-    #[inline(always)]
-    fn digit_count(self) -> usize {
-        fast_digit_count(self)
-    }
-}
-
-#[inline(always)]
-pub const fn fast_log2_u64(x: u64) -> usize {
-    const BITS: usize = u64::BITS as usize;
-    BITS - 1 - (x | 1).leading_zeros() as usize
-}
-
-#[inline(always)]
-pub fn fast_log10_u64(x: u64) -> usize {
-    let log2 = fast_log2_u64(x);
-    (log2 * 1233) >> 12
-}
-
-#[inline(always)]
-pub fn fallback_digit_count_u64(x: u64, table: &[u64]) -> usize {
-    // This value is always within 1: calculate if we need to round-up
-    // based on a pre-computed table.
-    let log10 = fast_log10_u64(x);
-    let shift_up = table.get(log10).map_or(false, |&y| x >= y);
-
-    log10 + shift_up as usize + 1
-}
-
-impl DigitCount for u64 {
-    #[inline(always)]
-    fn digit_count(self) -> usize {
-        const TABLE: [u64; 19] = [
-            10,
-            100,
-            1000,
-            10000,
-            100000,
-            1000000,
-            10000000,
-            100000000,
-            1000000000,
-            10000000000,
-            100000000000,
-            1000000000000,
-            10000000000000,
-            100000000000000,
-            1000000000000000,
-            10000000000000000,
-            100000000000000000,
-            1000000000000000000,
-            10000000000000000000,
-        ];
-        fallback_digit_count_u64(self, &TABLE)
+// Generate a table of every value with `digits` digits, including leading
+// zeros, so the digits for `x` are at `table[digits * x..digits * (x + 1)]`.
+const fn base10_table<const SIZE: usize>(digits: usize) -> [u8; SIZE] {
+    let mut table = [b'0'; SIZE];
+    let mut value = 0;
+    while value * digits < SIZE {
+        let mut x = value;
+        let mut index = digits;
+        while index > 0 {
+            index -= 1;
+            table[value * digits + index] = b'0' + (x % 10) as u8;
+            x /= 10;
+        }
+        value += 1;
     }
+    table
 }
 
-#[inline(always)]
-pub const fn fast_log2_u128(x: u128) -> usize {
-    const BITS: usize = u128::BITS as usize;
-    BITS - 1 - (x | 1).leading_zeros() as usize
-}
-
-#[inline(always)]
-pub fn fast_log10_u128(x: u128) -> usize {
-    let log2 = fast_log2_u128(x);
-    (log2 * 1233) >> 12
-}
-
-#[inline(always)]
-pub fn fallback_digit_count_u128(x: u128, table: &[u128]) -> usize {
-    // This value is always within 1: calculate if we need to round-up
-    // based on a pre-computed table.
-    let log10 = fast_log10_u128(x);
-    let shift_up = table.get(log10).map_or(false, |&y| x >= y);
-
-    log10 + shift_up as usize + 1
-}
-
-impl DigitCount for u128 {
-    #[inline(always)]
-    fn digit_count(self) -> usize {
-        const TABLE: [u128; 38] = [
-            10,
-            100,
-            1000,
-            10000,
-            100000,
-            1000000,
-            10000000,
-            100000000,
-            1000000000,
-            10000000000,
-            100000000000,
-            1000000000000,
-            10000000000000,
-            100000000000000,
-            1000000000000000,
-            10000000000000000,
-            100000000000000000,
-            1000000000000000000,
-            10000000000000000000,
-            100000000000000000000,
-            1000000000000000000000,
-            10000000000000000000000,
-            100000000000000000000000,
-            1000000000000000000000000,
-            10000000000000000000000000,
-            100000000000000000000000000,
-            1000000000000000000000000000,
-            10000000000000000000000000000,
-            100000000000000000000000000000,
-            1000000000000000000000000000000,
-            10000000000000000000000000000000,
-            100000000000000000000000000000000,
-            1000000000000000000000000000000000,
-            10000000000000000000000000000000000,
-            100000000000000000000000000000000000,
-            1000000000000000000000000000000000000,
-            10000000000000000000000000000000000000,
-            100000000000000000000000000000000000000,
-        ];
-        fallback_digit_count_u128(self, &TABLE)
-    }
-}
+// These are statics, and not consts, so every algorithm shares the same
+// memory, which is required to measure their cache footprint.
+pub static DIGIT_TO_BASE10_CUBED: [u8; 3000] = base10_table(3);
+pub static DIGIT_TO_BASE10_POW4: [u8; 40000] = base10_table(4);
 
 #[inline(always)]
 pub fn copy_to_dst<T: Copy, Bytes: AsRef<[T]>>(dst: &mut [T], src: Bytes) -> usize {
@@ -239,6 +63,29 @@ macro_rules! i {
     };
 }
 
+macro_rules! write_digit_i {
+    ($buffer:ident, $index:ident, $digit:expr, $checked:expr) => {{
+        let digit = $digit;
+        if $checked {
+            $buffer[$index] = digit;
+        } else {
+            unsafe {
+                let ptr = $buffer.get_unchecked_mut($index) as *mut u8;
+                core::ptr::write(ptr, digit);
+            }
+        }
+        $index += 1;
+    }};
+}
+
+macro_rules! write_digits_i {
+    ($buffer:ident, $index:ident, $r:expr, $table:ident, $checked:expr) => {{
+        let r = $r as usize;
+        write_digit_i!($buffer, $index, *i!($table[r]), $checked);
+        write_digit_i!($buffer, $index, *i!($table[r + 1]), $checked);
+    }};
+}
+
 #[macro_export]
 macro_rules! write_digit {
     ($buffer:ident, $index:expr, $digit:expr, $checked:expr) => {{
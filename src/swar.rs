@@ -0,0 +1,84 @@
+//! SWAR (SIMD within a register) writers, which convert 8 digits at once.
+//!
+//! The value is split into 4-digit lanes, then 2-digit, then 1-digit lanes
+//! of a single `u64`, using a multiplication by a reciprocal for each
+//! division, so all the lanes are converted in parallel without a table.
+
+use crate::digit_count::DigitCount;
+
+/// Convert a value in `0..10^8` to exactly 8 ASCII digits, with leading zeros.
+#[inline(always)]
+pub const fn swar8(value: u32) -> [u8; 8] {
+    debug_assert!(value < 1_0000_0000);
+    let value = value as u64;
+    // 4-digit lanes, with the high digits first in memory.
+    let quads = (value / 1_0000) | ((value % 1_0000) << 32);
+    // 2-digit lanes: `(x * 10486) >> 20 == x / 100` for all `x < 10^4`.
+    let hundreds = ((quads * 10486) >> 20) & 0x0000_007F_0000_007F;
+    let pairs = hundreds | ((quads - 100 * hundreds) << 16);
+    // 1-digit lanes: `(x * 103) >> 10 == x / 10` for all `x < 179`.
+    let tens = ((pairs * 103) >> 10) & 0x000F_000F_000F_000F;
+    let digits = tens | ((pairs - 10 * tens) << 8);
+    (digits | 0x3030_3030_3030_3030).to_le_bytes()
+}
+
+// Write the last `count` of the 8 digits of `value` forward.
+macro_rules! write_swar_i {
+    ($buffer:ident, $index:ident, $value:expr, $count:expr, $checked:expr) => {{
+        let digits = swar8($value);
+        let count: usize = $count;
+        if $checked {
+            $buffer[$index..$index + count].copy_from_slice(&digits[8 - count..]);
+        } else {
+            unsafe {
+                let src = digits.as_ptr().add(8 - count);
+                let dst = $buffer.as_mut_ptr().add($index);
+                core::ptr::copy_nonoverlapping(src, dst, count);
+            }
+        }
+        $index += count;
+    }};
+}
+
+// Writes up to 8 digits, and then 8 digits at a time.
+#[inline(always)]
+pub fn swar32<const CHECKED: bool>(n: u32, buffer: &mut [u8]) -> &mut [u8] {
+    let buffer = &mut buffer[..10];
+    const E8: u32 = 1_0000_0000;
+    let mut index = 0;
+    if n < E8 {
+        write_swar_i!(buffer, index, n, n.digit_count(), CHECKED);
+    } else {
+        // 9 or 10 digits.
+        let hi = n / E8;
+        write_swar_i!(buffer, index, hi, hi.digit_count(), CHECKED);
+        write_swar_i!(buffer, index, n % E8, 8, CHECKED);
+    }
+    &mut buffer[..index]
+}
+
+// Writes up to 8 digits, and then 8 digits at a time.
+#[inline(always)]
+pub fn swar64<const CHECKED: bool>(n: u64, buffer: &mut [u8]) -> &mut [u8] {
+    let buffer = &mut buffer[..20];
+    const E8: u64 = 1_0000_0000;
+    const E16: u64 = E8 * E8;
+    let mut index = 0;
+    if n < E8 {
+        let n = n as u32;
+        write_swar_i!(buffer, index, n, n.digit_count(), CHECKED);
+    } else if n < E16 {
+        // 9-16 digits.
+        let hi = (n / E8) as u32;
+        write_swar_i!(buffer, index, hi, hi.digit_count(), CHECKED);
+        write_swar_i!(buffer, index, (n % E8) as u32, 8, CHECKED);
+    } else {
+        // 17-20 digits.
+        let hi = (n / E16) as u32;
+        let lo = n % E16;
+        write_swar_i!(buffer, index, hi, hi.digit_count(), CHECKED);
+        write_swar_i!(buffer, index, (lo / E8) as u32, 8, CHECKED);
+        write_swar_i!(buffer, index, (lo % E8) as u32, 8, CHECKED);
+    }
+    &mut buffer[..index]
+}
@@ -0,0 +1,141 @@
+//! Variants of the Alexandrescu and jeaiii algorithms using larger tables.
+//!
+//! These write 3 or 4 digits per lookup, from a 3 KB and 40 KB table,
+//! respectively, rather than 2 digits from a 200 byte table. This reduces
+//! the number of divisions and writes at the cost of cache footprint.
+
+use crate::digit_count::{fast_digit_count, DigitCount};
+use crate::shared::{DIGIT_TO_BASE10_CUBED, DIGIT_TO_BASE10_POW4};
+
+// Copy `count` digits from the table at `r` to the buffer at `index`.
+macro_rules! copy_digits {
+    ($buffer:ident, $index:expr, $table:ident, $r:expr, $count:expr, $checked:expr) => {{
+        let index = $index;
+        let r = $r;
+        let count = $count;
+        let src = i!($table[r..r + count]);
+        if $checked {
+            $buffer[index..index + count].copy_from_slice(src);
+        } else {
+            unsafe {
+                let dst = $buffer.as_mut_ptr().add(index);
+                core::ptr::copy_nonoverlapping(src.as_ptr(), dst, count);
+            }
+        }
+    }};
+}
+
+macro_rules! alexandrescu_table {
+    ($name:ident, $t:ty, $table:ident, $digits:literal, $factor:literal) => {
+        #[inline(always)]
+        pub fn $name<const CHECKED: bool>(mut value: $t, buffer: &mut [u8]) -> &mut [u8] {
+            let count = value.digit_count();
+            let buffer = &mut buffer[..count];
+            let mut index = buffer.len();
+
+            // Decode all but the leading digits using the full table entry.
+            while value >= $factor {
+                let r = value % $factor;
+                value /= $factor;
+                index -= $digits;
+                copy_digits!(buffer, index, $table, r as usize * $digits, $digits, CHECKED);
+            }
+
+            // The remaining digits are the end of the table entry, which skips
+            // any leading zeros.
+            let r = value as usize * $digits + $digits - index;
+            copy_digits!(buffer, 0, $table, r, index, CHECKED);
+
+            buffer
+        }
+    };
+}
+
+alexandrescu_table!(alexandrescu32_table3, u32, DIGIT_TO_BASE10_CUBED, 3, 1000);
+alexandrescu_table!(alexandrescu64_table3, u64, DIGIT_TO_BASE10_CUBED, 3, 1000);
+alexandrescu_table!(alexandrescu32_table4, u32, DIGIT_TO_BASE10_POW4, 4, 10000);
+alexandrescu_table!(alexandrescu64_table4, u64, DIGIT_TO_BASE10_POW4, 4, 10000);
+
+// 109951163 = ceil(2^40 / 10^4). This is exact for all values below 10^8,
+// since `(10^8 - 1) * (109951163 * 10^4 - 2^40) < 2^40`, and 40 bits leaves
+// enough room to multiply the fraction by 10^4 within 64 bits.
+const TABLE4_MULTIPLIER: u64 = 109951163;
+const TABLE4_SHIFT: u32 = 40;
+const TABLE4_MASK: u64 = (1u64 << TABLE4_SHIFT) - 1;
+
+// Write 1-4 digits without leading zeros, returning the number of digits.
+#[inline(always)]
+fn write_leading4<const CHECKED: bool>(n: u32, buffer: &mut [u8]) -> usize {
+    let count = fast_digit_count(n);
+    let r = n as usize * 4 + 4 - count;
+    copy_digits!(buffer, 0, DIGIT_TO_BASE10_POW4, r, count, CHECKED);
+    count
+}
+
+// Write exactly 8 digits, including leading zeros.
+#[inline(always)]
+fn write_8_table4<const CHECKED: bool>(n: u32, buffer: &mut [u8]) {
+    let buffer = &mut buffer[..8];
+    let mut prod = n as u64 * TABLE4_MULTIPLIER;
+    let r = (prod >> TABLE4_SHIFT) as usize * 4;
+    copy_digits!(buffer, 0, DIGIT_TO_BASE10_POW4, r, 4, CHECKED);
+    prod = (prod & TABLE4_MASK) * 10000;
+    let r = (prod >> TABLE4_SHIFT) as usize * 4;
+    copy_digits!(buffer, 4, DIGIT_TO_BASE10_POW4, r, 4, CHECKED);
+}
+
+// Version of the jeaiii algorithm extracting 4 digits at a time.
+#[inline(always)]
+pub fn jeaiii32_table4<const CHECKED: bool>(n: u32, buffer: &mut [u8]) -> &mut [u8] {
+    let buffer = &mut buffer[..10];
+    if n < 1_0000 {
+        // 1-4 digits.
+        let count = write_leading4::<CHECKED>(n, buffer);
+        &mut buffer[..count]
+    } else if n < 1_0000_0000 {
+        // 5-8 digits.
+        let mut prod = n as u64 * TABLE4_MULTIPLIER;
+        let count = write_leading4::<CHECKED>((prod >> TABLE4_SHIFT) as u32, buffer);
+        prod = (prod & TABLE4_MASK) * 10000;
+        let r = (prod >> TABLE4_SHIFT) as usize * 4;
+        copy_digits!(buffer, count, DIGIT_TO_BASE10_POW4, r, 4, CHECKED);
+        &mut buffer[..count + 4]
+    } else {
+        // 9-10 digits.
+        let hi = n / 1_0000_0000;
+        let lo = n % 1_0000_0000;
+        let count = write_leading4::<CHECKED>(hi, buffer);
+        write_8_table4::<CHECKED>(lo, &mut buffer[count..]);
+        &mut buffer[..count + 8]
+    }
+}
+
+// Version of the jeaiii algorithm extracting 4 digits at a time, splitting
+// values larger than 32 bits into 8-digit chunks.
+#[inline(always)]
+pub fn jeaiii64_table4<const CHECKED: bool>(n: u64, buffer: &mut [u8]) -> &mut [u8] {
+    const FACTOR: u64 = 1_0000_0000;
+    if n <= u32::MAX as u64 {
+        return jeaiii32_table4::<CHECKED>(n as u32, buffer);
+    }
+
+    let buffer = &mut buffer[..20];
+    if n < FACTOR * FACTOR {
+        // 10-16 digits.
+        let hi = (n / FACTOR) as u32;
+        let lo = (n % FACTOR) as u32;
+        let count = jeaiii32_table4::<CHECKED>(hi, buffer).len();
+        write_8_table4::<CHECKED>(lo, &mut buffer[count..]);
+        &mut buffer[..count + 8]
+    } else {
+        // 17-20 digits.
+        let top = (n / (FACTOR * FACTOR)) as u32;
+        let rest = n % (FACTOR * FACTOR);
+        let mid = (rest / FACTOR) as u32;
+        let lo = (rest % FACTOR) as u32;
+        let count = write_leading4::<CHECKED>(top, buffer);
+        write_8_table4::<CHECKED>(mid, &mut buffer[count..]);
+        write_8_table4::<CHECKED>(lo, &mut buffer[count + 8..]);
+        &mut buffer[..count + 16]
+    }
+}
@@ -0,0 +1,124 @@
+//! A buffered `std::io::Write` sink for integers.
+//!
+//! Each integer is written directly into an internal fixed buffer using the
+//! fast writers, which is only written to the inner writer when full, or on
+//! flush. This avoids `write_fmt`, and the per-call overhead of `BufWriter`.
+
+use std::io;
+use std::mem::ManuallyDrop;
+use std::ptr;
+
+use crate::display::DecimalInt;
+
+// The size of the internal buffer, the same as the default for `BufWriter`.
+const BUFFER_SIZE: usize = 8 * 1024;
+
+// The maximum bytes for a single integer, the minus sign and 39 digits.
+const MAX_INT_SIZE: usize = 40;
+
+/// Write integers in decimal to an `io::Write`, in large chunks.
+///
+/// Any buffered data is flushed when dropped, ignoring errors. Use
+/// `into_inner` or `flush` to handle them.
+pub struct DecimalWriter<W: io::Write> {
+    inner: W,
+    buffer: [u8; BUFFER_SIZE],
+    len: usize,
+}
+
+impl<W: io::Write> DecimalWriter<W> {
+    /// Create a new writer wrapping `inner`.
+    #[inline]
+    pub fn new(inner: W) -> Self {
+        Self {
+            inner,
+            buffer: [0u8; BUFFER_SIZE],
+            len: 0,
+        }
+    }
+
+    /// Get a reference to the inner writer.
+    #[inline]
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Get the data written but not yet flushed to the inner writer.
+    #[inline]
+    pub fn buffer(&self) -> &[u8] {
+        &self.buffer[..self.len]
+    }
+
+    /// Write an integer in decimal.
+    #[inline]
+    pub fn write_int<T: DecimalInt>(&mut self, value: T) -> io::Result<()> {
+        if BUFFER_SIZE - self.len < MAX_INT_SIZE {
+            self.flush_buffer()?;
+        }
+        let buffer = &mut self.buffer[self.len..];
+        let mut index = 0;
+        if !value.is_nonnegative() {
+            buffer[0] = b'-';
+            index = 1;
+        }
+        let count = value.write_abs(&mut buffer[index..]).len();
+        self.len += index + count;
+        Ok(())
+    }
+
+    /// Write an integer in decimal, followed by a separator, such as `b'\n'`.
+    #[inline]
+    pub fn write_int_sep<T: DecimalInt>(&mut self, value: T, sep: u8) -> io::Result<()> {
+        if BUFFER_SIZE - self.len <= MAX_INT_SIZE {
+            self.flush_buffer()?;
+        }
+        self.write_int(value)?;
+        self.buffer[self.len] = sep;
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Flush the writer, and return the inner writer.
+    pub fn into_inner(mut self) -> io::Result<W> {
+        self.flush_buffer()?;
+        let this = ManuallyDrop::new(self);
+        // SAFETY: `this` is never dropped or used again, and the buffer
+        // doesn't need to be dropped, so this is moved out exactly once.
+        Ok(unsafe { ptr::read(&this.inner) })
+    }
+
+    // Write the buffered data to the inner writer.
+    fn flush_buffer(&mut self) -> io::Result<()> {
+        self.inner.write_all(&self.buffer[..self.len])?;
+        self.len = 0;
+        Ok(())
+    }
+}
+
+impl<W: io::Write> io::Write for DecimalWriter<W> {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if buf.len() > BUFFER_SIZE - self.len {
+            self.flush_buffer()?;
+        }
+        if buf.len() >= BUFFER_SIZE {
+            self.inner.write(buf)
+        } else {
+            self.buffer[self.len..self.len + buf.len()].copy_from_slice(buf);
+            self.len += buf.len();
+            Ok(buf.len())
+        }
+    }
+
+    #[inline]
+    fn flush(&mut self) -> io::Result<()> {
+        self.flush_buffer()?;
+        self.inner.flush()
+    }
+}
+
+impl<W: io::Write> Drop for DecimalWriter<W> {
+    fn drop(&mut self) {
+        _ = self.flush_buffer();
+    }
+}
@@ -0,0 +1,73 @@
+use algorithms::{AdaptiveWriter, Strategy};
+use proptest::prelude::*;
+
+fn check_value(writer: &mut AdaptiveWriter, value: u64) {
+    let mut buffer = [b'\x00'; 32];
+    let expected = value.to_string();
+    assert_eq!(writer.write_u64::<true>(value, &mut buffer), expected.as_bytes());
+    assert_eq!(writer.write_u64::<false>(value, &mut buffer), expected.as_bytes());
+}
+
+#[test]
+fn strategy_tests() {
+    let mut values = vec![0, 1, 9, 10, u32::MAX as u64, u32::MAX as u64 + 1, u64::MAX];
+    for exp in 1..20 {
+        let power = 10u64.pow(exp);
+        values.extend_from_slice(&[power - 1, power, power + 1]);
+    }
+    for &strategy in Strategy::ALL.iter() {
+        let mut writer = AdaptiveWriter::with_strategy(strategy);
+        for &value in values.iter() {
+            check_value(&mut writer, value);
+        }
+        // Too few values to sample a full window.
+        assert_eq!(writer.strategy(), strategy);
+        assert_eq!(writer.counters().evaluations, 0);
+        let index = Strategy::ALL.iter().position(|&x| x == strategy).unwrap();
+        assert_eq!(writer.counters().writes[index], 2 * values.len() as u64);
+    }
+}
+
+#[test]
+fn counter_tests() {
+    // Alternate between a small and large distribution, to force evaluations.
+    let mut writer = AdaptiveWriter::new();
+    let mut total = 0;
+    for phase in 0..4u64 {
+        for x in 0..100_000u64 {
+            let value = if phase % 2 == 0 { x % 1000 } else { u64::MAX - x * 7919 };
+            check_value(&mut writer, value);
+            total += 2;
+        }
+    }
+    let counters = writer.counters();
+    assert_eq!(counters.writes.iter().sum::<u64>(), total);
+    assert_eq!(counters.digit_counts.iter().sum::<u64>(), total / 16);
+    assert_eq!(counters.digit_counts[0], 0);
+    assert!(counters.digit_counts[3] > 0);
+    assert!(counters.digit_counts[20] > 0);
+    assert!(counters.evaluations >= 4);
+    assert!(counters.switches <= counters.evaluations);
+}
+
+#[test]
+fn reevaluate_tests() {
+    // The same distribution throughout, so only the first window changes the
+    // histogram, but the decision is still periodically re-evaluated.
+    let mut writer = AdaptiveWriter::new();
+    let mut buffer = [b'\x00'; 32];
+    for x in 0..1_000_000u64 {
+        writer.write_u64::<true>(x % 1000, &mut buffer);
+    }
+    assert!(writer.counters().evaluations >= 2);
+}
+
+proptest! {
+    #[test]
+    fn adaptive_proptest(values in prop::collection::vec(any::<u64>(), 1..2000)) {
+        let mut writer = AdaptiveWriter::new();
+        for value in values {
+            check_value(&mut writer, value);
+        }
+    }
+}
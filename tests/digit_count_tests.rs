@@ -0,0 +1,73 @@
+use algorithms::DigitCount;
+use proptest::prelude::*;
+
+// Check every power of 2 and 10, and the values adjacent to them, which
+// are the boundaries for each table entry.
+macro_rules! boundary_cases {
+    ($name:ident, $t:ty) => {
+        #[test]
+        fn $name() {
+            let mut values = vec![<$t>::MIN, <$t>::MAX, 0 as $t];
+            for exp in 0..<$t>::BITS {
+                values.push(1 << exp);
+            }
+            let mut power: $t = 1;
+            while let Some(next) = power.checked_mul(10) {
+                values.push(power);
+                power = next;
+            }
+            values.push(power);
+
+            for value in values {
+                for x in [value.wrapping_sub(1), value, value.wrapping_add(1)] {
+                    assert_eq!(x.digit_count(), x.to_string().len(), "{}", x);
+                    let y = (0 as $t).wrapping_sub(x);
+                    assert_eq!(y.digit_count(), y.to_string().len(), "{}", y);
+                }
+            }
+        }
+    };
+}
+
+boundary_cases!(u8_boundary_tests, u8);
+boundary_cases!(u16_boundary_tests, u16);
+boundary_cases!(u32_boundary_tests, u32);
+boundary_cases!(u64_boundary_tests, u64);
+boundary_cases!(u128_boundary_tests, u128);
+boundary_cases!(i8_boundary_tests, i8);
+boundary_cases!(i16_boundary_tests, i16);
+boundary_cases!(i32_boundary_tests, i32);
+boundary_cases!(i64_boundary_tests, i64);
+boundary_cases!(i128_boundary_tests, i128);
+
+#[test]
+fn small_exhaustive_tests() {
+    for x in u16::MIN..=u16::MAX {
+        assert_eq!(x.digit_count(), x.to_string().len());
+    }
+    for x in i16::MIN..=i16::MAX {
+        assert_eq!(x.digit_count(), x.to_string().len());
+    }
+}
+
+proptest! {
+    #[test]
+    fn u64_proptest(i in u64::MIN..=u64::MAX) {
+        prop_assert_eq!(i.digit_count(), i.to_string().len());
+    }
+
+    #[test]
+    fn u128_proptest(i in u128::MIN..=u128::MAX) {
+        prop_assert_eq!(i.digit_count(), i.to_string().len());
+    }
+
+    #[test]
+    fn i64_proptest(i in i64::MIN..=i64::MAX) {
+        prop_assert_eq!(i.digit_count(), i.to_string().len());
+    }
+
+    #[test]
+    fn i128_proptest(i in i128::MIN..=i128::MAX) {
+        prop_assert_eq!(i.digit_count(), i.to_string().len());
+    }
+}
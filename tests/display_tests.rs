@@ -0,0 +1,128 @@
+use std::fmt::Write;
+
+use algorithms::Decimal;
+use proptest::prelude::*;
+
+// Compare every flag combination against the standard library.
+macro_rules! assert_display {
+    ($x:expr, $width:expr, $($fmt:literal)*) => {{
+        let x = $x;
+        let width = $width;
+        $(
+            assert_eq!(
+                format!($fmt, Decimal(x), width = width),
+                format!($fmt, x, width = width),
+                "{} with {}",
+                x,
+                $fmt,
+            );
+        )*
+    }};
+}
+
+macro_rules! check_display {
+    ($name:ident, $t:ty) => {
+        fn $name(x: $t) {
+            assert_eq!(Decimal(x).to_string(), x.to_string());
+            assert_eq!(format!("{:+}", Decimal(x)), format!("{:+}", x));
+
+            let mut string = String::new();
+            algorithms::write_decimal(&mut string, x).unwrap();
+            assert_eq!(string, x.to_string());
+
+            for width in [0, 1, 2, 3, 5, 10, 20, 45] {
+                assert_display!(
+                    x,
+                    width,
+                    "{:width$}"
+                    "{:+width$}"
+                    "{:0width$}"
+                    "{:+0width$}"
+                    "{:<width$}"
+                    "{:^width$}"
+                    "{:>width$}"
+                    "{:+<width$}"
+                    "{:+^width$}"
+                    "{:+>width$}"
+                    "{:*<width$}"
+                    "{:*^width$}"
+                    "{:*>width$}"
+                    "{:_<+width$}"
+                    "{:_^+width$}"
+                    "{:_>+width$}"
+                    "{:<0width$}"
+                    "{:^0width$}"
+                    "{:>+0width$}"
+                    "{:é^width$}"
+                );
+            }
+        }
+    };
+}
+
+check_display!(check_u8, u8);
+check_display!(check_u16, u16);
+check_display!(check_u32, u32);
+check_display!(check_u64, u64);
+check_display!(check_u128, u128);
+check_display!(check_i8, i8);
+check_display!(check_i16, i16);
+check_display!(check_i32, i32);
+check_display!(check_i64, i64);
+check_display!(check_i128, i128);
+
+#[test]
+fn small_exhaustive_tests() {
+    (u8::MIN..=u8::MAX).for_each(check_u8);
+    (i8::MIN..=i8::MAX).for_each(check_i8);
+}
+
+#[test]
+fn boundary_tests() {
+    [0, 9, 10, u16::MAX].iter().copied().for_each(check_u16);
+    [i16::MIN, -1, 0, 1, i16::MAX].iter().copied().for_each(check_i16);
+    [0, 9, 10, u32::MAX].iter().copied().for_each(check_u32);
+    [i32::MIN, -1, 0, 1, i32::MAX].iter().copied().for_each(check_i32);
+    [0, 9, 10, u64::MAX].iter().copied().for_each(check_u64);
+    [i64::MIN, -1, 0, 1, i64::MAX].iter().copied().for_each(check_i64);
+    [0, 9, 10, u128::MAX].iter().copied().for_each(check_u128);
+    [i128::MIN, -1, 0, 1, i128::MAX].iter().copied().for_each(check_i128);
+}
+
+#[test]
+fn write_decimal_tests() {
+    let mut string = String::new();
+    algorithms::write_decimal(&mut string, 12u8).unwrap();
+    string.push(',');
+    algorithms::write_decimal(&mut string, -34i64).unwrap();
+    string.push(',');
+    write!(string, "{:>4}", Decimal(56u32)).unwrap();
+    assert_eq!(string, "12,-34,  56");
+}
+
+proptest! {
+    #[test]
+    fn u32_proptest(i in u32::MIN..=u32::MAX) {
+        check_u32(i);
+    }
+
+    #[test]
+    fn i32_proptest(i in i32::MIN..=i32::MAX) {
+        check_i32(i);
+    }
+
+    #[test]
+    fn u64_proptest(i in u64::MIN..=u64::MAX) {
+        check_u64(i);
+    }
+
+    #[test]
+    fn i64_proptest(i in i64::MIN..=i64::MAX) {
+        check_i64(i);
+    }
+
+    #[test]
+    fn i128_proptest(i in i128::MIN..=i128::MAX) {
+        check_i128(i);
+    }
+}
@@ -0,0 +1,159 @@
+//! Exhaustive correctness checks for every writer taking a 32-bit or
+//! smaller integer.
+//!
+//! The u32 checks cover all 2^32 inputs and are therefore opt-in: run them
+//! with `cargo test --release --test exhaustive_tests -- --ignored`. The u8
+//! and u16 checks are cheap and always run. Every value is checked with
+//! both the checked and the unchecked writers.
+
+use std::thread;
+
+// Increment a right-aligned, zero-padded decimal counter, returning the
+// new index of the first significant digit.
+#[inline(always)]
+fn increment(digits: &mut [u8; 10], mut start: usize) -> usize {
+    let mut index = digits.len();
+    while index > 0 {
+        index -= 1;
+        if digits[index] == b'9' {
+            digits[index] = b'0';
+        } else {
+            digits[index] += 1;
+            break;
+        }
+    }
+    if index < start {
+        start = index;
+    }
+    start
+}
+
+// Find the first value in `lo..=hi` where either writer does not match the
+// reference. Fixed-width writers are compared including the leading zeros.
+fn check_range<Checked, Unchecked>(
+    lo: u32,
+    hi: u32,
+    checked: &Checked,
+    unchecked: &Unchecked,
+    fixed: bool,
+) -> Option<u32>
+where
+    Checked: Fn(u32, &mut [u8]) -> &mut [u8],
+    Unchecked: Fn(u32, &mut [u8]) -> &mut [u8],
+{
+    let mut digits = [b'0'; 10];
+    let formatted = lo.to_string();
+    let mut start = digits.len() - formatted.len();
+    digits[start..].copy_from_slice(formatted.as_bytes());
+
+    let mut buffer = [b'\x00'; 16];
+    let mut value = lo;
+    loop {
+        let expected = if fixed {
+            &digits[..]
+        } else {
+            &digits[start..]
+        };
+        if checked(value, &mut buffer) != expected || unchecked(value, &mut buffer) != expected {
+            return Some(value);
+        }
+        if value == hi {
+            return None;
+        }
+        value += 1;
+        start = increment(&mut digits, start);
+    }
+}
+
+// Check every u32, split across all available threads, and return the
+// smallest failing value.
+fn check_u32<Checked, Unchecked>(checked: Checked, unchecked: Unchecked, fixed: bool) -> Option<u32>
+where
+    Checked: Fn(u32, &mut [u8]) -> &mut [u8] + Sync,
+    Unchecked: Fn(u32, &mut [u8]) -> &mut [u8] + Sync,
+{
+    let threads = thread::available_parallelism().map_or(1, |x| x.get()) as u64;
+    let total = u32::MAX as u64 + 1;
+    let step = total.div_ceil(threads);
+    let checked = &checked;
+    let unchecked = &unchecked;
+    thread::scope(|scope| {
+        let handles: Vec<_> = (0..threads)
+            .map(|i| i * step)
+            .filter(|&lo| lo < total)
+            .map(|lo| {
+                let hi = (lo + step).min(total) - 1;
+                scope.spawn(move || check_range(lo as u32, hi as u32, checked, unchecked, fixed))
+            })
+            .collect();
+        handles.into_iter().filter_map(|handle| handle.join().unwrap()).min()
+    })
+}
+
+macro_rules! u32_exhaustive {
+    ($name:ident, $func:ident, $fixed:expr) => {
+        #[test]
+        #[ignore]
+        fn $name() {
+            let checked = algorithms::$func::<true>;
+            let unchecked = algorithms::$func::<false>;
+            let first = check_u32(checked, unchecked, $fixed);
+            assert!(
+                first.is_none(),
+                "{} failed, first invalid value is {}",
+                stringify!($func),
+                first.unwrap()
+            );
+        }
+    };
+}
+
+u32_exhaustive!(naive_temp32_exhaustive, naive_temp32, false);
+u32_exhaustive!(naive_exact32_exhaustive, naive_exact32, false);
+u32_exhaustive!(alexandrescu32_exhaustive, alexandrescu32, false);
+u32_exhaustive!(jeaiii32_original_exhaustive, jeaiii32_original, false);
+u32_exhaustive!(jeaiii32_better_exhaustive, jeaiii32_better, false);
+u32_exhaustive!(jeaiii32_10_exhaustive, jeaiii32_10, true);
+u32_exhaustive!(jeaiii32_10_start_exhaustive, jeaiii32_10_start, false);
+u32_exhaustive!(jeaiii32_digits_exhaustive, jeaiii32_digits, false);
+u32_exhaustive!(jeaiii32_generic_exhaustive, jeaiii32_generic, false);
+u32_exhaustive!(alexandrescu32_table3_exhaustive, alexandrescu32_table3, false);
+u32_exhaustive!(alexandrescu32_table4_exhaustive, alexandrescu32_table4, false);
+u32_exhaustive!(jeaiii32_table4_exhaustive, jeaiii32_table4, false);
+u32_exhaustive!(alexandrescu32_arith_exhaustive, alexandrescu32_arith, false);
+u32_exhaustive!(jeaiii32_arith_exhaustive, jeaiii32_arith, false);
+u32_exhaustive!(swar32_exhaustive, swar32, false);
+
+macro_rules! small_exhaustive {
+    ($name:ident, $t:ty, $func:ident) => {
+        #[test]
+        fn $name() {
+            let mut buffer = [b'\x00'; 16];
+            let first = (<$t>::MIN..=<$t>::MAX).find(|&x| {
+                let expected = x.to_string();
+                algorithms::$func::<true>(x, &mut buffer) != expected.as_bytes()
+                    || algorithms::$func::<false>(x, &mut buffer) != expected.as_bytes()
+            });
+            assert!(
+                first.is_none(),
+                "{} failed, first invalid value is {}",
+                stringify!($func),
+                first.unwrap()
+            );
+        }
+    };
+}
+
+small_exhaustive!(naive_temp8_exhaustive, u8, naive_temp8);
+small_exhaustive!(naive_exact8_exhaustive, u8, naive_exact8);
+small_exhaustive!(alexandrescu8_exhaustive, u8, alexandrescu8);
+small_exhaustive!(jeaiii8_better_exhaustive, u8, jeaiii8_better);
+small_exhaustive!(jeaiii8as32_better_exhaustive, u8, jeaiii8as32_better);
+small_exhaustive!(jeaiii8_generic_exhaustive, u8, jeaiii8_generic);
+
+small_exhaustive!(naive_temp16_exhaustive, u16, naive_temp16);
+small_exhaustive!(naive_exact16_exhaustive, u16, naive_exact16);
+small_exhaustive!(alexandrescu16_exhaustive, u16, alexandrescu16);
+small_exhaustive!(jeaiii16_better_exhaustive, u16, jeaiii16_better);
+small_exhaustive!(jeaiii16as32_better_exhaustive, u16, jeaiii16as32_better);
+small_exhaustive!(jeaiii16_generic_exhaustive, u16, jeaiii16_generic);
@@ -0,0 +1,131 @@
+use algorithms::FormattedLen;
+use proptest::prelude::*;
+
+// Format the value in a radix using the standard library.
+fn to_radix(value: i128, radix: u32) -> String {
+    let mut digits = Vec::new();
+    let mut abs = value.unsigned_abs();
+    loop {
+        digits.push(std::char::from_digit((abs % radix as u128) as u32, radix).unwrap());
+        abs /= radix as u128;
+        if abs == 0 {
+            break;
+        }
+    }
+    if value < 0 {
+        digits.push('-');
+    }
+    digits.iter().rev().collect::<String>().to_ascii_uppercase()
+}
+
+macro_rules! len_cases {
+    ($name:ident, $t:ty, $decimal:ident, $padded:ident, $radix:ident, $len:ident) => {
+        fn $name(x: $t) {
+            let mut buffer = [b'\x00'; 160];
+            let expected = x.to_string();
+            assert_eq!(x.formatted_len(), expected.len());
+            assert_eq!(algorithms::formatted_len(x), expected.len());
+            assert_eq!(algorithms::$decimal::<true>(x, &mut buffer), expected.as_bytes());
+
+            for width in [0, 1, 5, 20, 45] {
+                let expected = format!("{:0width$}", x, width = width);
+                assert_eq!(x.formatted_len_padded(width), expected.len());
+                let written = algorithms::$padded::<true>(x, width, &mut buffer);
+                assert_eq!(written, expected.as_bytes());
+                let written = algorithms::$padded::<false>(x, width, &mut buffer);
+                assert_eq!(written, expected.as_bytes());
+            }
+
+            for radix in [2, 8, 10, 16, 36] {
+                let expected = to_radix(x as i128, radix);
+                assert_eq!(x.formatted_len_radix(radix), expected.len());
+                let written = algorithms::$radix::<true>(x, radix, &mut buffer);
+                assert_eq!(written, expected.as_bytes());
+            }
+
+            let expected = x.to_string();
+            if !expected.starts_with('-') {
+                let count = x.formatted_len();
+                let written = algorithms::$len::<true>(x as _, count, &mut buffer);
+                assert_eq!(written, expected.as_bytes());
+            }
+        }
+    };
+}
+
+len_cases!(check_u8, u8, alexandrescu8, alexandrescu_padded8, naive_radix8, alexandrescu8_len);
+len_cases!(check_u16, u16, alexandrescu16, alexandrescu_padded16, naive_radix16, alexandrescu16_len);
+len_cases!(check_u32, u32, alexandrescu32, alexandrescu_padded32, naive_radix32, alexandrescu32_len);
+len_cases!(check_u64, u64, alexandrescu64, alexandrescu_padded64, naive_radix64, naive_exact64_len);
+len_cases!(check_i8, i8, alexandrescu_i8, alexandrescu_padded_i8, naive_radix_i8, alexandrescu8_len);
+len_cases!(check_i16, i16, alexandrescu_i16, alexandrescu_padded_i16, naive_radix_i16, alexandrescu16_len);
+len_cases!(check_i32, i32, alexandrescu_i32, alexandrescu_padded_i32, naive_radix_i32, alexandrescu32_len);
+len_cases!(check_i64, i64, alexandrescu_i64, alexandrescu_padded_i64, naive_radix_i64, naive_exact64_len);
+
+#[test]
+fn small_exhaustive_tests() {
+    (u8::MIN..=u8::MAX).for_each(check_u8);
+    (i8::MIN..=i8::MAX).for_each(check_i8);
+    (u16::MIN..=u16::MAX).for_each(check_u16);
+    (i16::MIN..=i16::MAX).for_each(check_i16);
+}
+
+#[test]
+fn boundary_tests() {
+    for x in [0, 1, 9, 10, 99, 100, u32::MAX / 2, u32::MAX - 1, u32::MAX] {
+        check_u32(x);
+    }
+    for x in [i32::MIN, i32::MIN + 1, -100, -99, -10, -9, -1, 0, 1, 9, 10, i32::MAX] {
+        check_i32(x);
+    }
+    for x in [0, 9, 10, u64::MAX / 2, u64::MAX - 1, u64::MAX] {
+        check_u64(x);
+    }
+    for x in [i64::MIN, i64::MIN + 1, -10, -9, -1, 0, 9, 10, i64::MAX] {
+        check_i64(x);
+    }
+}
+
+#[test]
+fn u128_tests() {
+    let mut buffer = [b'\x00'; 160];
+    for x in [0, 9, 10, u64::MAX as u128, u128::MAX - 1, u128::MAX] {
+        assert_eq!(x.formatted_len(), x.to_string().len());
+        let expected = format!("{:b}", x);
+        assert_eq!(x.formatted_len_radix(2), expected.len());
+        assert_eq!(algorithms::naive_radix128::<true>(x, 2, &mut buffer), expected.as_bytes());
+    }
+    for x in [i128::MIN, -1, 0, 1, i128::MAX] {
+        let expected = x.to_string();
+        assert_eq!(x.formatted_len(), expected.len());
+        assert_eq!(algorithms::alexandrescu_i128::<true>(x, &mut buffer), expected.as_bytes());
+        let expected = format!("{:045}", x);
+        let written = algorithms::alexandrescu_padded_i128::<true>(x, 45, &mut buffer);
+        assert_eq!(written, expected.as_bytes());
+        let expected = to_radix(x, 2);
+        assert_eq!(x.formatted_len_radix(2), expected.len());
+        assert_eq!(algorithms::naive_radix_i128::<true>(x, 2, &mut buffer), expected.as_bytes());
+    }
+}
+
+proptest! {
+    #[test]
+    fn u32_proptest(i in u32::MIN..=u32::MAX) {
+        check_u32(i);
+    }
+
+    #[test]
+    fn i32_proptest(i in i32::MIN..=i32::MAX) {
+        check_i32(i);
+    }
+
+    #[test]
+    fn u64_proptest(i in u64::MIN..=u64::MAX) {
+        check_u64(i);
+    }
+
+    #[test]
+    fn i64_proptest(i in i64::MIN..=i64::MAX) {
+        check_i64(i);
+    }
+}
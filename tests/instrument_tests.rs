@@ -0,0 +1,80 @@
+use algorithms::{BranchCounters, INSTRUMENTED};
+
+// Write every value, and get the hits for each branch.
+fn hits<T: Copy>(
+    counters: &BranchCounters,
+    values: &[T],
+    write: impl Fn(T, &mut [u8]) -> usize,
+) -> Vec<u64> {
+    let mut buffer = [b'\x00'; 32];
+    algorithms::reset_branch_counters();
+    values.iter().for_each(|&x| _ = write(x, &mut buffer));
+    counters.hits()
+}
+
+// The counters are global, so every check runs in a single test.
+#[test]
+fn branch_counter_tests() {
+    let expected = |instrumented: Vec<u64>| {
+        if INSTRUMENTED {
+            instrumented
+        } else {
+            vec![0; instrumented.len()]
+        }
+    };
+
+    // One value for each digit count.
+    let values32: Vec<u32> = (0..10).map(|x| 10u32.pow(x)).collect();
+    let hits32 = |counters, write: fn(u32, &mut [u8]) -> &mut [u8]| {
+        hits(counters, &values32, |x, buffer| write(x, buffer).len())
+    };
+    assert_eq!(
+        hits32(&algorithms::JEAIII32_ORIGINAL, algorithms::jeaiii32_original::<true>),
+        expected(vec![1; 10])
+    );
+    assert_eq!(
+        hits32(&algorithms::JEAIII32_BETTER, algorithms::jeaiii32_better::<true>),
+        expected(vec![1, 1, 2, 2, 2, 1, 1])
+    );
+    assert_eq!(
+        hits32(&algorithms::JEAIII32_DIGITS, algorithms::jeaiii32_digits::<false>),
+        expected(vec![1, 1, 2, 2, 2, 1, 1])
+    );
+
+    let values64: Vec<u64> = (0..20).map(|x| 10u64.pow(x)).chain([u64::MAX]).collect();
+    let hits64 = |counters, write: fn(u64, &mut [u8]) -> &mut [u8]| {
+        hits(counters, &values64, |x, buffer| write(x, buffer).len())
+    };
+    assert_eq!(
+        hits64(&algorithms::JEAIII64_BETTER, algorithms::jeaiii64_better::<true>),
+        expected(vec![10, 9, 2])
+    );
+    assert_eq!(
+        hits64(&algorithms::JEAIII64_BETTER_V2, algorithms::jeaiii64_better_v2::<true>),
+        expected(vec![10, 9, 2])
+    );
+    assert_eq!(
+        hits64(&algorithms::JEAIII64_BETTER_V3, algorithms::jeaiii64_better_v3::<true>),
+        expected(vec![10, 11])
+    );
+    assert_eq!(
+        hits64(&algorithms::JEAIII64_BETTER_V4, algorithms::jeaiii64_better_v4::<true>),
+        expected(vec![10, 9, 2])
+    );
+    assert_eq!(
+        hits64(&algorithms::JEAIII64_BETTER_V5, algorithms::jeaiii64_better_v5::<true>),
+        expected(vec![10, 11])
+    );
+    assert_eq!(
+        hits64(&algorithms::JEAIII64_BETTER_V6, algorithms::jeaiii64_better_v6::<true>),
+        expected(vec![1, 1, 2, 2, 2, 1, 1, 11])
+    );
+
+    // The 64-bit writers count the nested 32-bit branches.
+    let nested = hits(&algorithms::JEAIII32_BETTER, &values64, |x, buffer| {
+        algorithms::jeaiii64_better_v5::<true>(x, buffer).len()
+    });
+    assert_eq!(nested, expected(vec![1, 1, 2, 2, 2, 1, 1]));
+    algorithms::reset_branch_counters();
+    assert_eq!(algorithms::JEAIII32_BETTER.total(), 0);
+}
@@ -0,0 +1,47 @@
+#![cfg(feature = "serde")]
+
+use proptest::prelude::*;
+use serde_json::json;
+
+#[test]
+fn json_tests() {
+    let value = json!({
+        "id": 18446744073709551615u64,
+        "min": -9223372036854775808i64,
+        "zero": 0,
+        "values": [1, -1, 10, -10, 4294967295u32, -2147483648i32],
+        "name": "test \"quoted\"\n",
+        "float": 1.5,
+        "nested": {"ok": true, "none": null},
+    });
+    let expected = serde_json::to_string(&value).unwrap();
+    assert_eq!(algorithms::to_json_string(&value).unwrap(), expected);
+    assert_eq!(algorithms::to_json_vec(&value).unwrap(), expected.into_bytes());
+}
+
+#[test]
+fn int_tests() {
+    macro_rules! check {
+        ($($x:expr)*) => ($(
+            let x = $x;
+            assert_eq!(algorithms::to_json_string(&x).unwrap(), serde_json::to_string(&x).unwrap());
+        )*);
+    }
+
+    check! {
+        u8::MIN u8::MAX u16::MAX u32::MAX u64::MAX u128::MAX
+        i8::MIN i8::MAX i16::MIN i32::MIN i64::MIN i128::MIN i128::MAX
+    }
+}
+
+proptest! {
+    #[test]
+    fn u64_proptest(values in proptest::collection::vec(u64::MIN..=u64::MAX, 0..50)) {
+        prop_assert_eq!(algorithms::to_json_string(&values).unwrap(), serde_json::to_string(&values).unwrap());
+    }
+
+    #[test]
+    fn i64_proptest(values in proptest::collection::vec(i64::MIN..=i64::MAX, 0..50)) {
+        prop_assert_eq!(algorithms::to_json_string(&values).unwrap(), serde_json::to_string(&values).unwrap());
+    }
+}
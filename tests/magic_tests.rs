@@ -0,0 +1,147 @@
+use algorithms::{jeaiii_magic, Magic};
+
+// Check the magic is exact over the range, and spot check the boundaries.
+fn assert_exact(magic: Magic, lo: u128, hi: u128) {
+    assert!(magic.is_exact_over(lo, hi), "{:?} is not exact over {}..={}", magic, lo, hi);
+    assert!(magic.check(lo));
+    assert!(magic.check(hi));
+}
+
+#[test]
+fn jeaiii32_original_magic_tests() {
+    // 3 or 4 digits.
+    assert_exact(Magic::new(42949673, 32, 0, 2, 64), 100, 9999);
+    // 5 or 6 digits.
+    assert_exact(Magic::new(429497, 32, 0, 4, 64), 1_0000, 99_9999);
+    // 7 or 8 digits.
+    assert_exact(Magic::new(140737489, 47, 0, 6, 64), 100_0000, 9999_9999);
+    // 9 or 10 digits.
+    assert_exact(Magic::new(1441151881, 57, 0, 8, 64), 1_0000_0000, u32::MAX as u128);
+}
+
+#[test]
+fn jeaiii32_better_magic_tests() {
+    assert_exact(Magic::new(42949673, 32, 0, 2, 64), 100, 9999);
+    assert_exact(Magic::new(429497, 32, 0, 4, 64), 1_0000, 99_9999);
+    assert_exact(Magic::new(281474978, 48, 16, 6, 64), 100_0000, 9999_9999);
+    // 9 digits: this requires the `+ 1`, since the truncation from the
+    // pre-shift isn't compensated for small values otherwise.
+    assert_exact(Magic::new(1441151882, 57, 25, 8, 64), 1_0000_0000, 9_9999_9999);
+    assert!(!Magic::new(1441151881, 57, 25, 8, 64).is_exact_over(1_0000_0000, 9_9999_9999));
+    // 10 digits: the `+ 1` overestimates too much for large values.
+    assert_exact(Magic::new(1441151881, 57, 25, 8, 64), 10_0000_0000, u32::MAX as u128);
+    assert!(!Magic::new(1441151882, 57, 25, 8, 64).is_exact_over(10_0000_0000, u32::MAX as u128));
+}
+
+#[test]
+fn jeaiii32_10_magic_tests() {
+    let magic = Magic::new(1441151881, 57, 0, 8, 64);
+    assert_exact(magic, 0, u32::MAX as u128);
+    let max = magic.max_input().unwrap();
+    assert_eq!(max, 59_6895_3945);
+    assert!(magic.check(max));
+    assert!(!magic.check(max + 1));
+}
+
+#[test]
+fn jeaiii64_better_v6_magic_tests() {
+    // 10 digits: only exact up to `MAX_10`, and not up to `2 * u32::MAX`.
+    let magic = Magic::new(1441151881, 57, 25, 8, 64);
+    assert_exact(magic, 10_0000_0000, 59_6895_3945);
+    assert_eq!(magic.max_input(), Some(59_6895_3945));
+    assert!(!magic.is_exact_over(10_0000_0000, 2 * u32::MAX as u128 - 1));
+}
+
+#[test]
+fn jeaiii32_table4_magic_tests() {
+    // The fraction is multiplied by 10^4, rather than 100, so only check the
+    // rounding error is never carried into the integer bits.
+    let magic = Magic::new(109951163, 40, 0, 4, 64);
+    assert_eq!(magic.error(), Some(2224));
+    assert!((1_0000_0000 - 1) * magic.error().unwrap() < 1 << 40);
+}
+
+#[test]
+fn jeaiii_arith_magic_tests() {
+    // The branches of `jeaiii32_arith`.
+    assert_exact(jeaiii_magic::<u32, u64, 4>(), 100, 9999);
+    assert_exact(jeaiii_magic::<u32, u64, 6>(), 1_0000, 99_9999);
+    assert_exact(jeaiii_magic::<u32, u64, 8>(), 100_0000, 9999_9999);
+    assert_exact(jeaiii_magic::<u32, u64, 9>(), 1_0000_0000, 9_9999_9999);
+    assert_exact(jeaiii_magic::<u32, u64, 10>(), 10_0000_0000, u32::MAX as u128);
+    // The 8-digit chunks of `jeaiii64_arith`, including leading zeros.
+    assert_exact(jeaiii_magic::<u32, u64, 8>(), 0, 9999_9999);
+}
+
+// Check the constants of the generic core are exact for every value of `T`
+// with up to `N` digits, for each `N` the writers are instantiated with.
+macro_rules! assert_generic {
+    ($t:ty, $w:ty, $($n:literal)*) => {$(
+        let max = (10u128.pow($n) - 1).min(<$t>::MAX as u128);
+        assert_exact(jeaiii_magic::<$t, $w, $n>(), 0, max);
+    )*};
+}
+
+#[test]
+fn jeaiii_generic_magic_tests() {
+    assert_generic!(u8, u64, 2 3);
+    assert_generic!(u16, u64, 2 4 5);
+    assert_generic!(u32, u64, 2 4 6 8 9 10);
+    assert_generic!(u64, u128, 10 12 14 16 18 19);
+}
+
+#[test]
+fn max_input_exact_tests() {
+    // Without a pre-shift, the computed range is exact.
+    for digits in 1..=10 {
+        let magic = Magic::find_bounded(digits, 10u128.pow(digits) - 1, 64);
+        if let Some(magic) = magic {
+            let max = magic.max_input().unwrap();
+            assert!(magic.check(max));
+            if max < u32::MAX as u128 {
+                assert!(!magic.check(max + 1));
+            }
+        }
+    }
+}
+
+#[test]
+fn find_tests() {
+    assert_eq!(Magic::find(2, 64), Some(Magic::new(1, 0, 0, 0, 64)));
+    assert_eq!(Magic::find(4, 64), Some(Magic::new(5243, 19, 0, 2, 64)));
+    assert_eq!(Magic::find(4, 32), Some(Magic::new(5243, 19, 0, 2, 32)));
+    // There are no constants exact for all 10 digits in 64 bits.
+    assert_eq!(Magic::find(10, 64), None);
+    assert!(Magic::find_bounded(10, u32::MAX as u128, 64).is_some());
+    assert!(Magic::find(10, 128).is_some());
+    // There are constants exact for all 19 digits in 128 bits.
+    assert!(Magic::find(19, 128).is_some());
+    // But not for all u64 values in 128 bits.
+    assert_eq!(Magic::find_bounded(20, u64::MAX as u128, 128), None);
+
+    for word_bits in [32, 64, 128] {
+        for digits in 1..=20 {
+            if let Some(magic) = Magic::find(digits, word_bits) {
+                let max = 10u128.pow(digits) - 1;
+                assert!(magic.is_exact_over(0, max));
+                assert!(magic.check(max));
+                assert!(magic.check(max / 3));
+                // Must be the smallest shift.
+                if magic.shift > 0 {
+                    let scale = 10u128.pow(magic.power);
+                    let shift = magic.shift - 1;
+                    let smaller = Magic::new((1u128 << shift).div_ceil(scale), shift, 0, magic.power, word_bits);
+                    assert!(!smaller.is_exact_over(0, max));
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn check_exhaustive_tests() {
+    let magic = Magic::find(6, 64).unwrap();
+    assert!((0..100_0000u128).all(|x| magic.check(x)));
+    let magic = Magic::new(429497, 32, 0, 4, 64);
+    assert!((0..100_0000u128).all(|x| magic.check(x)));
+}
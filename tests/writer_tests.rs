@@ -0,0 +1,64 @@
+use std::io::Write;
+
+use algorithms::DecimalWriter;
+use proptest::prelude::*;
+
+#[test]
+fn write_int_tests() {
+    let mut writer = DecimalWriter::new(Vec::new());
+    writer.write_int(0u8).unwrap();
+    writer.write_int_sep(-12i32, b',').unwrap();
+    writer.write_int_sep(u64::MAX, b',').unwrap();
+    writer.write_int(i128::MIN).unwrap();
+    writer.write_all(b"!").unwrap();
+    assert!(writer.get_ref().is_empty());
+    let expected = format!("0-12,{},{}!", u64::MAX, i128::MIN);
+    assert_eq!(writer.buffer(), expected.as_bytes());
+    assert_eq!(writer.into_inner().unwrap(), expected.as_bytes());
+}
+
+#[test]
+fn flush_tests() {
+    // Write enough to flush the internal buffer multiple times.
+    let mut expected = Vec::new();
+    let mut writer = DecimalWriter::new(Vec::new());
+    for x in (0..100_000u64).map(|x| x * 7919) {
+        writer.write_int_sep(x, b'\n').unwrap();
+        writeln!(expected, "{}", x).unwrap();
+    }
+    assert!(!writer.get_ref().is_empty());
+    writer.flush().unwrap();
+    assert!(writer.buffer().is_empty());
+    assert_eq!(writer.get_ref(), &expected);
+
+    // Large writes bypass the buffer.
+    let large = vec![b'1'; 20_000];
+    writer.write_int(2u8).unwrap();
+    writer.write_all(&large).unwrap();
+    expected.push(b'2');
+    expected.extend_from_slice(&large);
+    assert_eq!(writer.into_inner().unwrap(), expected);
+}
+
+#[test]
+fn drop_tests() {
+    let mut output = Vec::new();
+    {
+        let mut writer = DecimalWriter::new(&mut output);
+        writer.write_int(-5i8).unwrap();
+    }
+    assert_eq!(output, b"-5");
+}
+
+proptest! {
+    #[test]
+    fn i64_proptest(values in proptest::collection::vec(i64::MIN..=i64::MAX, 0..500)) {
+        let mut writer = DecimalWriter::new(Vec::new());
+        let mut expected = String::new();
+        for &x in values.iter() {
+            writer.write_int_sep(x, b' ').unwrap();
+            expected.push_str(&format!("{} ", x));
+        }
+        prop_assert_eq!(writer.into_inner().unwrap(), expected.into_bytes());
+    }
+}